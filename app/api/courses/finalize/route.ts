@@ -98,7 +98,6 @@ export async function POST(request: NextRequest) {
     // ON-CHAIN: Finalize course (mints bonus XP on-chain)
     // ──────────────────────────────────────────────
     let onchainTxId: string | null = null
-    let onchainError: string | null = null
 
     const signer = tryCreateBackendSigner()
     if (signer) {
@@ -112,8 +111,15 @@ export async function POST(request: NextRequest) {
 
         devLog('onchain', '✅ Course finalized on-chain!', { txId: onchainTxId })
       } catch (err) {
-        onchainError = err instanceof Error ? err.message : String(err)
-        devLog('onchain', '⚠️ On-chain finalize failed, falling back to DB-only:', onchainError)
+        // The chain is the source of truth once a signer is configured: marking
+        // the course complete here would award DB XP for a finalize that never
+        // happened, so report the failure and let the learner retry.
+        const onchainError = err instanceof Error ? err.message : String(err)
+        devLog('onchain', '❌ On-chain finalize failed:', onchainError)
+        return NextResponse.json(
+          { error: 'On-chain finalize failed', details: onchainError },
+          { status: 502 }
+        )
       }
     } else {
       devLog('onchain', '⏭️ Signer not configured — DB-only mode')
@@ -199,7 +205,6 @@ export async function POST(request: NextRequest) {
         readyForCredential: true,
         onchain: {
           txId: onchainTxId,
-          error: onchainError,
           mode: onchainTxId ? 'on-chain' : 'db-only',
         },
         sync: {
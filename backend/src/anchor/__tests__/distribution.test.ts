@@ -0,0 +1,43 @@
+import { describe, it, expect } from 'vitest';
+import { PublicKey } from '@solana/web3.js';
+import { BN } from '@coral-xyz/anchor';
+import { buildDistributionTree, distributionLeaf, DistributionEntry } from '../distribution';
+
+// Shared with the `utils::tests` module in programs/academy so the tree
+// builder and the on-chain verifier can't drift apart.
+const SHARED_ROOT = 'f5e7cb5628fb18b6019ab3ccecfe0e9bf56bb714a73854eac9731179cbf036d5';
+
+function entry(index: number, byte: number, amount: number): DistributionEntry {
+  return {
+    index,
+    learner: new PublicKey(Buffer.alloc(32, byte)),
+    amount: new BN(amount),
+  };
+}
+
+const SHARED_ENTRIES = [entry(0, 1, 100), entry(1, 2, 200), entry(2, 3, 300)];
+
+describe('buildDistributionTree', () => {
+  it('matches the root the program test pins', () => {
+    const { root } = buildDistributionTree(SHARED_ENTRIES);
+    expect(root.toString('hex')).toBe(SHARED_ROOT);
+  });
+
+  it('carries the odd leaf up without a sibling', () => {
+    const { proofs } = buildDistributionTree(SHARED_ENTRIES);
+    const [a, b, c] = SHARED_ENTRIES.map(distributionLeaf);
+    expect(proofs[0]).toEqual([b, c]);
+    expect(proofs[1]).toEqual([a, c]);
+    expect(proofs[2]).toHaveLength(1);
+  });
+
+  it('uses the leaf as the root for a single entry', () => {
+    const { root, proofs } = buildDistributionTree([entry(0, 1, 100)]);
+    expect(root).toEqual(distributionLeaf(entry(0, 1, 100)));
+    expect(proofs).toEqual([[]]);
+  });
+
+  it('rejects an empty distribution', () => {
+    expect(() => buildDistributionTree([])).toThrow();
+  });
+});
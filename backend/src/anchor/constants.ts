@@ -20,8 +20,16 @@ export const PDA_SEEDS = {
   MINTER_ROLE: 'minter_role',
   ACHIEVEMENT_TYPE: 'achievement_type',
   ACHIEVEMENT_RECEIPT: 'achievement_receipt',
+  BACKEND_SIGNER: 'backend_signer',
+  LEADERBOARD: 'leaderboard',
+  GUILD_MEMBERSHIP: 'guild_membership',
+  CREATOR_VAULT: 'creator_vault',
+  COMMITMENT_VAULT: 'commitment_vault',
 };
 
+// `Leaderboard::season` of the lifetime board
+export const LIFETIME_LEADERBOARD = 0;
+
 // XP Configuration
 export const XP_DECIMALS = 0; // Non-decimal XP tokens
 export const MAX_LESSONS = 256; // 4 × 64-bit slots = 256 lesson bitmap
@@ -0,0 +1,73 @@
+import { createHash } from 'crypto';
+import { PublicKey } from '@solana/web3.js';
+import { BN } from '@coral-xyz/anchor';
+
+/**
+ * One prize in a season reward distribution. `index` is the learner's
+ * position in the list and their bit in the on-chain claimed bitmap.
+ */
+export interface DistributionEntry {
+  index: number;
+  learner: PublicKey;
+  amount: BN;
+}
+
+function sha256(...parts: Buffer[]): Buffer {
+  const hash = createHash('sha256');
+  parts.forEach((part) => hash.update(part));
+  return hash.digest();
+}
+
+/**
+ * Leaf hash, matching `distribution_leaf` in the program
+ */
+export function distributionLeaf(entry: DistributionEntry): Buffer {
+  const index = Buffer.alloc(4);
+  index.writeUInt32LE(entry.index);
+  return sha256(
+    Buffer.from([0]),
+    index,
+    entry.learner.toBuffer(),
+    entry.amount.toArrayLike(Buffer, 'le', 8)
+  );
+}
+
+function hashPair(a: Buffer, b: Buffer): Buffer {
+  const [left, right] = Buffer.compare(a, b) <= 0 ? [a, b] : [b, a];
+  return sha256(Buffer.from([1]), left, right);
+}
+
+/**
+ * Build the merkle root and a proof per entry for `create_distribution`
+ * and `claim_distribution`. Pairs are hashed in sorted order, so proofs
+ * are plain sibling lists; an odd node is carried up unchanged.
+ */
+export function buildDistributionTree(entries: DistributionEntry[]): {
+  root: Buffer;
+  proofs: Buffer[][];
+} {
+  if (entries.length === 0) {
+    throw new Error('Distribution needs at least one entry');
+  }
+
+  let level = entries.map(distributionLeaf);
+  let positions = entries.map((_, i) => i);
+  const proofs: Buffer[][] = entries.map(() => []);
+
+  while (level.length > 1) {
+    const next: Buffer[] = [];
+    for (let i = 0; i < level.length; i += 2) {
+      next.push(i + 1 < level.length ? hashPair(level[i], level[i + 1]) : level[i]);
+    }
+    positions = positions.map((position, entry) => {
+      const sibling = position ^ 1;
+      if (sibling < level.length) {
+        proofs[entry].push(level[sibling]);
+      }
+      return position >> 1;
+    });
+    level = next;
+  }
+
+  return { root: level[0], proofs };
+}
@@ -1,7 +1,7 @@
 import { Program, AnchorProvider } from '@coral-xyz/anchor';
 import { Connection, PublicKey, Keypair, SystemProgram, Transaction, VersionedTransaction } from '@solana/web3.js';
 import BN from 'bn.js';
-import { TOKEN_2022_PROGRAM_ID, MPL_CORE_PROGRAM_ID } from './constants';
+import { TOKEN_2022_PROGRAM_ID, MPL_CORE_PROGRAM_ID, LIFETIME_LEADERBOARD } from './constants';
 import IDL from '../../../lib/anchor/academy.json' assert { type: 'json' };
 import { PROGRAM_ID } from './constants';
 import {
@@ -11,8 +11,22 @@ import {
   getMinterRolePda,
   getAchievementTypePda,
   getAchievementReceiptPda,
+  getBackendSignerRolePda,
+  getLeaderboardPda,
+  getGuildMembershipPda,
+  getCreatorVaultPda,
+  getCommitmentVaultPda,
 } from './pda';
-import { getAssociatedTokenAddressSync, createAssociatedTokenAccountInstruction } from '@solana/spl-token';
+import {
+  getAssociatedTokenAddressSync,
+  createAssociatedTokenAccountInstruction,
+  createAssociatedTokenAccountIdempotentInstruction,
+} from '@solana/spl-token';
+
+type AccountFetcher = {
+  fetch: (address: PublicKey) => Promise<unknown>;
+  fetchNullable: (address: PublicKey) => Promise<unknown | null>;
+};
 
 /**
  * Backend Signer Service
@@ -55,6 +69,83 @@ export class BackendSignerService {
     return this.backendSigner.publicKey;
   }
 
+  private get accounts(): Record<string, AccountFetcher> {
+    return this.program.account as unknown as Record<string, AccountFetcher>;
+  }
+
+  /**
+   * Accounts shared by every XP-minting instruction: the learner's XP (and,
+   * while a season runs, season XP) account, both leaderboards, the learner's
+   * guild and this signer's role, plus instructions creating the learner's
+   * token accounts if they don't exist yet.
+   */
+  private async xpAwardAccounts(learnerAddress: PublicKey, xpMintAddress: PublicKey) {
+    const [configPda] = getConfigPda();
+    const config = (await this.accounts.config.fetch(configPda)) as {
+      currentSeason: number;
+      seasonMint: PublicKey;
+      seasonActive: boolean;
+    };
+
+    const learnerTokenAccount = getAssociatedTokenAddressSync(
+      xpMintAddress,
+      learnerAddress,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const preInstructions = [
+      createAssociatedTokenAccountIdempotentInstruction(
+        this.backendSigner.publicKey,
+        learnerTokenAccount,
+        learnerAddress,
+        xpMintAddress,
+        TOKEN_2022_PROGRAM_ID
+      ),
+    ];
+
+    let learnerSeasonAccount: PublicKey | null = null;
+    if (config.seasonActive) {
+      learnerSeasonAccount = getAssociatedTokenAddressSync(
+        config.seasonMint,
+        learnerAddress,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+      preInstructions.push(
+        createAssociatedTokenAccountIdempotentInstruction(
+          this.backendSigner.publicKey,
+          learnerSeasonAccount,
+          learnerAddress,
+          config.seasonMint,
+          TOKEN_2022_PROGRAM_ID
+        )
+      );
+    }
+
+    const [guildMembership] = getGuildMembershipPda(learnerAddress);
+    const membership = (await this.accounts.guildMembership.fetchNullable(guildMembership)) as {
+      guild: PublicKey;
+    } | null;
+
+    return {
+      accounts: {
+        config: configPda,
+        learner: learnerAddress,
+        learnerTokenAccount,
+        xpMint: xpMintAddress,
+        seasonMint: config.seasonActive ? config.seasonMint : null,
+        learnerSeasonAccount,
+        leaderboard: getLeaderboardPda(LIFETIME_LEADERBOARD)[0],
+        seasonLeaderboard: config.seasonActive ? getLeaderboardPda(config.currentSeason)[0] : null,
+        guildMembership,
+        guild: membership && !membership.guild.equals(PublicKey.default) ? membership.guild : null,
+        backendSignerRole: getBackendSignerRolePda(this.backendSigner.publicKey)[0],
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      },
+      preInstructions,
+    };
+  }
+
   /**
    * Complete a lesson for a learner
    */
@@ -67,24 +158,18 @@ export class BackendSignerService {
     try {
       const [coursePda] = getCoursePda(courseId);
       const [enrollmentPda] = getEnrollmentPda(courseId, learnerAddress);
-      const [configPda] = getConfigPda();
-
-      // Get learner's XP token account
-      const learnerXpAccount = getAssociatedTokenAddressSync(xpMintAddress, learnerAddress);
+      const { accounts, preInstructions } = await this.xpAwardAccounts(learnerAddress, xpMintAddress);
 
       const signature = await (this.program.rpc as unknown as Record<string, CallableFunction>).completeLesson(
-        new BN(lessonIndex),
+        lessonIndex,
         {
           accounts: {
-            learner: learnerAddress,
-            course: coursePda,
+            lesson: { ...accounts, course: coursePda },
             enrollment: enrollmentPda,
-            config: configPda,
-            xpMint: xpMintAddress,
-            learnerXpAccount: learnerXpAccount,
-            tokenProgram: TOKEN_2022_PROGRAM_ID,
-            systemProgram: SystemProgram.programId,
+            backendSigner: this.backendSigner.publicKey,
           },
+          preInstructions,
+          signers: [this.backendSigner],
         }
       );
 
@@ -106,22 +191,54 @@ export class BackendSignerService {
     try {
       const [coursePda] = getCoursePda(courseId);
       const [enrollmentPda] = getEnrollmentPda(courseId, learnerAddress);
-      const [configPda] = getConfigPda();
+      const [creatorVault] = getCreatorVaultPda(coursePda);
+
+      const course = (await this.accounts.course.fetch(coursePda)) as {
+        commitment: { mint: PublicKey | null } | null;
+      };
+      const enrollment = (await this.accounts.enrollment.fetch(enrollmentPda)) as {
+        cohort: PublicKey | null;
+      };
+      const { accounts, preInstructions } = await this.xpAwardAccounts(learnerAddress, xpMintAddress);
 
-      // Get learner's XP token account
-      const learnerXpAccount = getAssociatedTokenAddressSync(xpMintAddress, learnerAddress);
+      // SPL commitment stakes are returned out of the course's commitment vault
+      let commitmentAccounts = {
+        learnerCommitmentAccount: null as PublicKey | null,
+        commitmentVault: null as PublicKey | null,
+        commitmentMint: null as PublicKey | null,
+        commitmentTokenProgram: null as PublicKey | null,
+      };
+      const commitmentMint = course.commitment?.mint;
+      if (commitmentMint) {
+        const mintInfo = await this.program.provider.connection.getAccountInfo(commitmentMint);
+        if (!mintInfo) throw new Error(`Commitment mint ${commitmentMint.toBase58()} not found`);
+        commitmentAccounts = {
+          learnerCommitmentAccount: getAssociatedTokenAddressSync(
+            commitmentMint,
+            learnerAddress,
+            false,
+            mintInfo.owner
+          ),
+          commitmentVault: getCommitmentVaultPda(coursePda)[0],
+          commitmentMint,
+          commitmentTokenProgram: mintInfo.owner,
+        };
+      }
 
       const signature = await (this.program.rpc as unknown as Record<string, CallableFunction>).finalizeCourse({
         accounts: {
-          learner: learnerAddress,
-          course: coursePda,
+          finalize: {
+            ...accounts,
+            ...commitmentAccounts,
+            course: coursePda,
+            creatorVault,
+            cohort: enrollment.cohort,
+          },
           enrollment: enrollmentPda,
-          config: configPda,
-          xpMint: xpMintAddress,
-          learnerXpAccount: learnerXpAccount,
-          tokenProgram: TOKEN_2022_PROGRAM_ID,
-          systemProgram: SystemProgram.programId,
+          backendSigner: this.backendSigner.publicKey,
         },
+        preInstructions,
+        signers: [this.backendSigner],
       });
 
       return signature;
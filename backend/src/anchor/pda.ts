@@ -1,6 +1,15 @@
+import { createHash } from 'crypto';
 import { PublicKey } from '@solana/web3.js';
 import { PDA_SEEDS, PROGRAM_ID } from './constants';
 
+/**
+ * Seed for a course or achievement id. Ids may exceed the 32-byte seed
+ * limit, so the program derives PDAs from their sha256 hash.
+ */
+export function idSeed(id: string): Buffer {
+  return createHash('sha256').update(id).digest();
+}
+
 /**
  * Get the Config PDA (global program configuration)
  */
@@ -16,7 +25,7 @@ export function getConfigPda(): [PublicKey, number] {
  */
 export function getCoursePda(courseId: string): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(PDA_SEEDS.COURSE), Buffer.from(courseId)],
+    [Buffer.from(PDA_SEEDS.COURSE), idSeed(courseId)],
     PROGRAM_ID
   );
 }
@@ -26,7 +35,7 @@ export function getCoursePda(courseId: string): [PublicKey, number] {
  */
 export function getEnrollmentPda(courseId: string, learnerAddress: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(PDA_SEEDS.ENROLLMENT), Buffer.from(courseId), learnerAddress.toBuffer()],
+    [Buffer.from(PDA_SEEDS.ENROLLMENT), idSeed(courseId), learnerAddress.toBuffer()],
     PROGRAM_ID
   );
 }
@@ -46,7 +55,7 @@ export function getMinterRolePda(minterAddress: PublicKey): [PublicKey, number]
  */
 export function getAchievementTypePda(achievementId: string): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(PDA_SEEDS.ACHIEVEMENT_TYPE), Buffer.from(achievementId)],
+    [Buffer.from(PDA_SEEDS.ACHIEVEMENT_TYPE), idSeed(achievementId)],
     PROGRAM_ID
   );
 }
@@ -56,7 +65,56 @@ export function getAchievementTypePda(achievementId: string): [PublicKey, number
  */
 export function getAchievementReceiptPda(achievementId: string, recipientAddress: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(PDA_SEEDS.ACHIEVEMENT_RECEIPT), Buffer.from(achievementId), recipientAddress.toBuffer()],
+    [Buffer.from(PDA_SEEDS.ACHIEVEMENT_RECEIPT), idSeed(achievementId), recipientAddress.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Get a Backend Signer Role PDA
+ */
+export function getBackendSignerRolePda(signerAddress: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PDA_SEEDS.BACKEND_SIGNER), signerAddress.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Get a Leaderboard PDA by season (season 0 is the lifetime board)
+ */
+export function getLeaderboardPda(season: number): [PublicKey, number] {
+  const seasonSeed = Buffer.alloc(4);
+  seasonSeed.writeUInt32LE(season);
+  return PublicKey.findProgramAddressSync([Buffer.from(PDA_SEEDS.LEADERBOARD), seasonSeed], PROGRAM_ID);
+}
+
+/**
+ * Get a Guild Membership PDA by learner
+ */
+export function getGuildMembershipPda(learnerAddress: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PDA_SEEDS.GUILD_MEMBERSHIP), learnerAddress.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Get a course's Creator Vault PDA
+ */
+export function getCreatorVaultPda(courseAddress: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PDA_SEEDS.CREATOR_VAULT), courseAddress.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Get a course's Commitment Vault PDA
+ */
+export function getCommitmentVaultPda(courseAddress: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PDA_SEEDS.COMMITMENT_VAULT), courseAddress.toBuffer()],
     PROGRAM_ID
   );
 }
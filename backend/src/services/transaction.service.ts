@@ -23,6 +23,7 @@ import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress, ASSOCIATED_TOKEN_PROGRAM_I
 import * as path from 'path'
 import * as fs from 'fs'
 import { fileURLToPath } from 'url'
+import { idSeed } from '../anchor/pda'
 
 const __dirname = path.dirname(fileURLToPath(import.meta.url))
 
@@ -149,8 +150,8 @@ export class TransactionService {
 
     const userKey = new PublicKey(userId)
     const config = this.getPda([Buffer.from('config')])
-    const course = this.getPda([Buffer.from('course'), Buffer.from(courseId)])
-    const enrollment = this.getPda([Buffer.from('enrollment'), Buffer.from(courseId), userKey.toBuffer()])
+    const course = this.getPda([Buffer.from('course'), idSeed(courseId)])
+    const enrollment = this.getPda([Buffer.from('enrollment'), idSeed(courseId), userKey.toBuffer()])
     const learner = this.getPda([Buffer.from('learner'), userKey.toBuffer()])
     const xpMintStr = process.env.XP_TOKEN_MINT || 'ACADBRCB3zGvo1KSCbkztS33ZNzeBv2d7bqGceti3ucf'
     let xpMint: PublicKey
@@ -192,9 +193,9 @@ export class TransactionService {
     }
 
     const userKey = new PublicKey(userId)
-    const course = this.getPda([Buffer.from('course'), Buffer.from(courseId)])
+    const course = this.getPda([Buffer.from('course'), idSeed(courseId)])
     const learner = this.getPda([Buffer.from('learner'), userKey.toBuffer()])
-    const enrollment = this.getPda([Buffer.from('enrollment'), Buffer.from(courseId), userKey.toBuffer()])
+    const enrollment = this.getPda([Buffer.from('enrollment'), idSeed(courseId), userKey.toBuffer()])
 
     try {
       return await this.program.methods
@@ -223,9 +224,9 @@ export class TransactionService {
 
     const userKey = new PublicKey(userId)
     const config = this.getPda([Buffer.from('config')])
-    const course = this.getPda([Buffer.from('course'), Buffer.from(courseId)])
+    const course = this.getPda([Buffer.from('course'), idSeed(courseId)])
     const learner = this.getPda([Buffer.from('learner'), userKey.toBuffer()])
-    const enrollment = this.getPda([Buffer.from('enrollment'), Buffer.from(courseId), userKey.toBuffer()])
+    const enrollment = this.getPda([Buffer.from('enrollment'), idSeed(courseId), userKey.toBuffer()])
 
     try {
       return await this.program.methods
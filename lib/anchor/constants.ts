@@ -50,8 +50,18 @@ export const PDA_SEEDS = {
   MINTER: 'minter',
   ACHIEVEMENT: 'achievement',
   ACHIEVEMENT_RECEIPT: 'achievement_receipt',
+  BACKEND_SIGNER: 'backend_signer',
+  LEADERBOARD: 'leaderboard',
+  GUILD_MEMBERSHIP: 'guild_membership',
+  CREATOR_VAULT: 'creator_vault',
+  COMMITMENT_VAULT: 'commitment_vault',
 } as const;
 
+/**
+ * `Leaderboard::season` of the lifetime board
+ */
+export const LIFETIME_LEADERBOARD = 0;
+
 /**
  * XP Configuration
  */
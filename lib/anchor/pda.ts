@@ -1,10 +1,19 @@
 import { PublicKey } from '@solana/web3.js';
+import { sha256 } from '@noble/hashes/sha256';
 import { PROGRAM_ID, PDA_SEEDS } from './constants';
 
 /**
  * PDA Derivation utilities for the On-chain Academy program
  */
 
+/**
+ * Seed for a course or achievement id. Ids may exceed the 32-byte seed
+ * limit, so the program derives PDAs from their sha256 hash.
+ */
+export function idSeed(id: string): Buffer {
+  return Buffer.from(sha256(id));
+}
+
 /**
  * Derives the Config PDA (singleton)
  */
@@ -20,7 +29,7 @@ export function getConfigPda(): [PublicKey, number] {
  */
 export function getCoursePda(courseId: string): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(PDA_SEEDS.COURSE), Buffer.from(courseId)],
+    [Buffer.from(PDA_SEEDS.COURSE), idSeed(courseId)],
     PROGRAM_ID
   );
 }
@@ -30,7 +39,7 @@ export function getCoursePda(courseId: string): [PublicKey, number] {
  */
 export function getEnrollmentPda(courseId: string, learner: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(PDA_SEEDS.ENROLLMENT), Buffer.from(courseId), learner.toBuffer()],
+    [Buffer.from(PDA_SEEDS.ENROLLMENT), idSeed(courseId), learner.toBuffer()],
     PROGRAM_ID
   );
 }
@@ -50,7 +59,7 @@ export function getMinterRolePda(minter: PublicKey): [PublicKey, number] {
  */
 export function getAchievementTypePda(achievementId: string): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(PDA_SEEDS.ACHIEVEMENT), Buffer.from(achievementId)],
+    [Buffer.from(PDA_SEEDS.ACHIEVEMENT), idSeed(achievementId)],
     PROGRAM_ID
   );
 }
@@ -62,9 +71,61 @@ export function getAchievementReceiptPda(achievementId: string, recipient: Publi
   return PublicKey.findProgramAddressSync(
     [
       Buffer.from(PDA_SEEDS.ACHIEVEMENT_RECEIPT),
-      Buffer.from(achievementId),
+      idSeed(achievementId),
       recipient.toBuffer(),
     ],
     PROGRAM_ID
   );
 }
+
+/**
+ * Derives a BackendSignerRole PDA
+ */
+export function getBackendSignerRolePda(signer: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PDA_SEEDS.BACKEND_SIGNER), signer.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Derives a Leaderboard PDA (season 0 is the lifetime board)
+ */
+export function getLeaderboardPda(season: number): [PublicKey, number] {
+  const seasonSeed = Buffer.alloc(4);
+  seasonSeed.writeUInt32LE(season);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PDA_SEEDS.LEADERBOARD), seasonSeed],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Derives a GuildMembership PDA
+ */
+export function getGuildMembershipPda(learner: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PDA_SEEDS.GUILD_MEMBERSHIP), learner.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Derives a course's CreatorVault PDA
+ */
+export function getCreatorVaultPda(course: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PDA_SEEDS.CREATOR_VAULT), course.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Derives a course's commitment vault PDA
+ */
+export function getCommitmentVaultPda(course: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PDA_SEEDS.COMMITMENT_VAULT), course.toBuffer()],
+    PROGRAM_ID
+  );
+}
@@ -119,3 +119,24 @@ export function getCompletedLessonIndices(lessonFlags: BN[], lessonCount: number
 export function isCourseComplete(lessonFlags: BN[], lessonCount: number): boolean {
   return countCompletedLessons(lessonFlags) === lessonCount;
 }
+
+/**
+ * I80F48 Fixed-Point Helpers
+ * `value` holds the raw 80.48 bits: one whole XP is `1 << 48`.
+ */
+
+export const I80F48_FRAC_BITS = 48;
+
+/**
+ * Encode whole XP as an I80F48 argument
+ */
+export function toI80F48(amount: number | BN): { value: BN } {
+  return { value: new BN(amount).shln(I80F48_FRAC_BITS) };
+}
+
+/**
+ * Decode an I80F48 field to whole XP, rounding down
+ */
+export function fromI80F48(fixed: { value: BN }): BN {
+  return fixed.value.shrn(I80F48_FRAC_BITS);
+}
@@ -40,9 +40,8 @@ export function useEnrollCourse() {
       const tx = await program.methods
         .enroll(courseId)
         .accountsPartial({
-          course: coursePda,
+          entry: { course: coursePda, learner: wallet.publicKey },
           enrollment: enrollmentPda,
-          learner: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
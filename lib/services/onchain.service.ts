@@ -1,6 +1,6 @@
 import { Program, AnchorProvider, BN } from '@coral-xyz/anchor';
 import { Connection, PublicKey, Keypair, SystemProgram, Transaction, VersionedTransaction } from '@solana/web3.js';
-import { TOKEN_2022_PROGRAM_ID, MPL_CORE_PROGRAM_ID, DEVNET } from '@/lib/anchor/constants';
+import { TOKEN_2022_PROGRAM_ID, MPL_CORE_PROGRAM_ID, DEVNET, LIFETIME_LEADERBOARD } from '@/lib/anchor/constants';
 import { getProgram } from '@/lib/anchor';
 import type { UntypedAccountAccess } from '@/lib/types/shared';
 import {
@@ -10,8 +10,18 @@ import {
   getMinterRolePda,
   getAchievementTypePda,
   getAchievementReceiptPda,
+  getBackendSignerRolePda,
+  getLeaderboardPda,
+  getGuildMembershipPda,
+  getCreatorVaultPda,
+  getCommitmentVaultPda,
 } from '@/lib/anchor/pda';
-import { getAssociatedTokenAddressSync, createAssociatedTokenAccountInstruction } from '@solana/spl-token';
+import { toI80F48 } from '@/lib/anchor/types';
+import {
+  getAssociatedTokenAddressSync,
+  createAssociatedTokenAccountInstruction,
+  createAssociatedTokenAccountIdempotentInstruction,
+} from '@solana/spl-token';
 
 /* ─── Dev logger (only in non-production) ─── */
 const isDev = process.env.NODE_ENV !== 'production';
@@ -57,6 +67,81 @@ export class BackendSignerService {
     this.program = getProgram(provider);
   }
 
+  /**
+   * Accounts shared by every XP-minting instruction: the learner's XP (and,
+   * while a season runs, season XP) account, both leaderboards, the learner's
+   * guild and this signer's role. Also returns instructions creating the
+   * learner's token accounts if they don't exist yet.
+   */
+  private async xpAwardAccounts(learnerAddress: PublicKey, xpMint: PublicKey) {
+    const accounts = this.program.account as unknown as UntypedAccountAccess;
+    const [configPda] = getConfigPda();
+    const config = (await accounts.config.fetch(configPda)) as unknown as {
+      currentSeason: number;
+      seasonMint: PublicKey;
+      seasonActive: boolean;
+    };
+
+    const learnerTokenAccount = getAssociatedTokenAddressSync(
+      xpMint,
+      learnerAddress,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const preInstructions = [
+      createAssociatedTokenAccountIdempotentInstruction(
+        this.backendSigner.publicKey,
+        learnerTokenAccount,
+        learnerAddress,
+        xpMint,
+        TOKEN_2022_PROGRAM_ID
+      ),
+    ];
+
+    let learnerSeasonAccount: PublicKey | null = null;
+    if (config.seasonActive) {
+      learnerSeasonAccount = getAssociatedTokenAddressSync(
+        config.seasonMint,
+        learnerAddress,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+      preInstructions.push(
+        createAssociatedTokenAccountIdempotentInstruction(
+          this.backendSigner.publicKey,
+          learnerSeasonAccount,
+          learnerAddress,
+          config.seasonMint,
+          TOKEN_2022_PROGRAM_ID
+        )
+      );
+    }
+
+    const [guildMembership] = getGuildMembershipPda(learnerAddress);
+    const membership = (await accounts.guildMembership.fetchNullable(guildMembership)) as unknown as {
+      guild: PublicKey;
+    } | null;
+    const guild = membership && !membership.guild.equals(PublicKey.default) ? membership.guild : null;
+
+    return {
+      accounts: {
+        config: configPda,
+        learner: learnerAddress,
+        learnerTokenAccount,
+        xpMint,
+        seasonMint: config.seasonActive ? config.seasonMint : null,
+        learnerSeasonAccount,
+        leaderboard: getLeaderboardPda(LIFETIME_LEADERBOARD)[0],
+        seasonLeaderboard: config.seasonActive ? getLeaderboardPda(config.currentSeason)[0] : null,
+        guildMembership,
+        guild,
+        backendSignerRole: getBackendSignerRolePda(this.backendSigner.publicKey)[0],
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      },
+      preInstructions,
+    };
+  }
+
   /**
    * Complete a lesson for a learner
    * Creates XP token account if needed, mints XP
@@ -67,43 +152,18 @@ export class BackendSignerService {
     learnerAddress: PublicKey,
     xpMint: PublicKey
   ): Promise<string> {
-    const [configPda] = getConfigPda();
     const [coursePda] = getCoursePda(courseId);
     const [enrollmentPda] = getEnrollmentPda(courseId, learnerAddress);
-
-    // Create learner XP ATA if needed
-    const learnerXpAta = getAssociatedTokenAddressSync(
-      xpMint,
-      learnerAddress,
-      false,
-      TOKEN_2022_PROGRAM_ID
-    );
-
-    const config = await (this.program.account as unknown as UntypedAccountAccess).config.fetch(configPda);
-    const course = await (this.program.account as unknown as UntypedAccountAccess).course.fetch(coursePda);
+    const { accounts, preInstructions } = await this.xpAwardAccounts(learnerAddress, xpMint);
 
     const tx = await this.program.methods
       .completeLesson(lessonIndex)
       .accountsPartial({
-        config: configPda,
-        course: coursePda,
+        lesson: { ...accounts, course: coursePda },
         enrollment: enrollmentPda,
-        learner: learnerAddress,
-        learnerTokenAccount: learnerXpAta,
-        xpMint: xpMint,
         backendSigner: this.backendSigner.publicKey,
-        tokenProgram: TOKEN_2022_PROGRAM_ID,
       })
-      .preInstructions([
-        // Create XP ATA if it doesn't exist
-        createAssociatedTokenAccountInstruction(
-          this.backendSigner.publicKey,
-          learnerXpAta,
-          learnerAddress,
-          xpMint,
-          TOKEN_2022_PROGRAM_ID
-        ),
-      ])
+      .preInstructions(preInstructions)
       .signers([this.backendSigner])
       .rpc({ skipPreflight: true });
 
@@ -112,47 +172,64 @@ export class BackendSignerService {
 
   /**
    * Finalize a course
-   * Verifies completion, awards bonus XP, triggers creator reward if threshold met
+   * Verifies completion, awards bonus XP, accrues the creator's share to the course's creator vault
    */
   async finalizeCourse(
     courseId: string,
     learnerAddress: PublicKey,
     xpMint: PublicKey
   ): Promise<string> {
-    const [configPda] = getConfigPda();
+    const programAccounts = this.program.account as unknown as UntypedAccountAccess;
     const [coursePda] = getCoursePda(courseId);
     const [enrollmentPda] = getEnrollmentPda(courseId, learnerAddress);
+    const [creatorVault] = getCreatorVaultPda(coursePda);
 
-    const course = await (this.program.account as unknown as UntypedAccountAccess).course.fetch(coursePda) as unknown as { creator: PublicKey };
-
-    const learnerXpAta = getAssociatedTokenAddressSync(
-      xpMint,
-      learnerAddress,
-      false,
-      TOKEN_2022_PROGRAM_ID
-    );
-
-    const creatorXpAta = getAssociatedTokenAddressSync(
-      xpMint,
-      course.creator,
-      false,
-      TOKEN_2022_PROGRAM_ID
-    );
+    const course = (await programAccounts.course.fetch(coursePda)) as unknown as {
+      commitment: { mint: PublicKey | null } | null;
+    };
+    const enrollment = (await programAccounts.enrollment.fetch(enrollmentPda)) as unknown as {
+      cohort: PublicKey | null;
+    };
+    const { accounts, preInstructions } = await this.xpAwardAccounts(learnerAddress, xpMint);
+
+    // SPL commitment stakes are returned out of the course's commitment vault
+    let commitmentAccounts = {
+      learnerCommitmentAccount: null as PublicKey | null,
+      commitmentVault: null as PublicKey | null,
+      commitmentMint: null as PublicKey | null,
+      commitmentTokenProgram: null as PublicKey | null,
+    };
+    const commitmentMint = course.commitment?.mint;
+    if (commitmentMint) {
+      const mintInfo = await this.program.provider.connection.getAccountInfo(commitmentMint);
+      if (!mintInfo) throw new Error(`Commitment mint ${commitmentMint.toBase58()} not found`);
+      commitmentAccounts = {
+        learnerCommitmentAccount: getAssociatedTokenAddressSync(
+          commitmentMint,
+          learnerAddress,
+          false,
+          mintInfo.owner
+        ),
+        commitmentVault: getCommitmentVaultPda(coursePda)[0],
+        commitmentMint,
+        commitmentTokenProgram: mintInfo.owner,
+      };
+    }
 
     const tx = await this.program.methods
       .finalizeCourse()
       .accountsPartial({
-        config: configPda,
-        course: coursePda,
+        finalize: {
+          ...accounts,
+          ...commitmentAccounts,
+          course: coursePda,
+          creatorVault,
+          cohort: enrollment.cohort,
+        },
         enrollment: enrollmentPda,
-        learner: learnerAddress,
-        learnerTokenAccount: learnerXpAta,
-        creatorTokenAccount: creatorXpAta,
-        creator: course.creator,
-        xpMint: xpMint,
         backendSigner: this.backendSigner.publicKey,
-        tokenProgram: TOKEN_2022_PROGRAM_ID,
       })
+      .preInstructions(preInstructions)
       .signers([this.backendSigner])
       .rpc({ skipPreflight: true });
 
@@ -263,7 +340,7 @@ export class BackendSignerService {
     );
 
     const tx = await this.program.methods
-      .rewardXp({ value: new BN(amount) }, reason)
+      .rewardXp(toI80F48(amount), reason)
       .accountsPartial({
         config: configPda,
         minterRole: minterRolePda,
@@ -54,4 +54,162 @@ pub enum AcademyError {
     InvalidMetadata = 6024,
     #[msg("Invalid lesson count")]
     InvalidLessonCount = 6025,
+    #[msg("Course role does not grant this permission")]
+    CourseRolePermissionDenied = 6026,
+    #[msg("Value exceeds course role bounds")]
+    CourseRoleBoundExceeded = 6027,
+    #[msg("Invalid course role permissions")]
+    InvalidCourseRolePermissions = 6028,
+    #[msg("Course has not been approved")]
+    CourseNotApproved = 6029,
+    #[msg("Course is not pending review")]
+    CourseNotPending = 6030,
+    #[msg("Invalid basis points")]
+    InvalidBasisPoints = 6031,
+    #[msg("Treasury mismatch")]
+    TreasuryMismatch = 6032,
+    #[msg("Invalid creator splits")]
+    InvalidCreatorSplits = 6033,
+    #[msg("Creator vault has no room left for removed co-creators' unclaimed rewards")]
+    CreatorRewardPending = 6034,
+    #[msg("Recipient has no share in this creator vault")]
+    CreatorShareNotFound = 6035,
+    #[msg("No creator reward to claim")]
+    NothingToClaim = 6036,
+    #[msg("Missing or malformed backend attestation")]
+    InvalidAttestation = 6037,
+    #[msg("Backend attestation has expired")]
+    AttestationExpired = 6038,
+    #[msg("Attestation nonce already used")]
+    NonceAlreadyUsed = 6039,
+    #[msg("Backend signer is outside its validity window")]
+    BackendSignerNotValid = 6040,
+    #[msg("Backend signer set is full")]
+    BackendSignerSetFull = 6041,
+    #[msg("Invalid validity window")]
+    InvalidValidityWindow = 6042,
+    #[msg("Lesson mask is empty")]
+    EmptyLessonMask = 6043,
+    #[msg("Amount exceeds the minter's rolling window cap")]
+    MinterWindowExceeded = 6044,
+    #[msg("Reward recipients and amounts do not line up")]
+    BatchLengthMismatch = 6045,
+    #[msg("Course has no price")]
+    CourseNotPriced = 6046,
+    #[msg("Payment accounts required for a priced course")]
+    MissingPaymentAccounts = 6047,
+    #[msg("Refund no longer available")]
+    RefundNotAvailable = 6048,
+    #[msg("Nothing escrowed for this enrollment")]
+    NothingEscrowed = 6049,
+    #[msg("Commitment accounts required for this course")]
+    MissingCommitmentAccounts = 6050,
+    #[msg("Commitment deposit still outstanding")]
+    CommitmentOutstanding = 6051,
+    #[msg("Commitment deadline has not passed")]
+    CommitmentDeadlineNotReached = 6052,
+    #[msg("Course does not take SPL commitments")]
+    CommitmentNotSpl = 6053,
+    #[msg("Enrollment has expired")]
+    EnrollmentExpired = 6054,
+    #[msg("Enrollment has not expired")]
+    EnrollmentNotExpired = 6055,
+    #[msg("Invalid cohort schedule")]
+    InvalidCohortSchedule = 6056,
+    #[msg("Cohort does not belong to this course")]
+    CohortMismatch = 6057,
+    #[msg("Cohort has ended")]
+    CohortEnded = 6058,
+    #[msg("Cohort is full")]
+    CohortFull = 6059,
+    #[msg("Cohort still has open seats")]
+    CohortNotFull = 6060,
+    #[msg("Cohort waitlist is disabled")]
+    WaitlistDisabled = 6061,
+    #[msg("Course does not allow restoring progress")]
+    ProgressRestoreDisabled = 6062,
+    #[msg("Archived attempt already completed the course")]
+    ArchiveAlreadyCompleted = 6063,
+    #[msg("Enrollment or completion record required")]
+    MissingCompletionProof = 6064,
+    #[msg("Account type cannot be migrated")]
+    AccountNotMigratable = 6065,
+    #[msg("Account is already at the current version")]
+    AccountAlreadyMigrated = 6066,
+    #[msg("Clawback exceeds the XP recorded for this minter")]
+    ClawbackExceedsMinted = 6067,
+    #[msg("Reward id must be a non-empty lowercase slug")]
+    InvalidRewardId = 6068,
+    #[msg("Invalid reward availability window")]
+    InvalidRewardWindow = 6069,
+    #[msg("Reward is not available for redemption")]
+    RewardNotAvailable = 6070,
+    #[msg("Reward supply exhausted")]
+    RewardSoldOut = 6071,
+    #[msg("Learner has reached the redemption limit for this reward")]
+    RedemptionLimitReached = 6072,
+    #[msg("Prize accounts required for this reward")]
+    MissingPrizeAccounts = 6073,
+    #[msg("Season numbers must increase")]
+    InvalidSeason = 6074,
+    #[msg("A season is already active")]
+    SeasonAlreadyActive = 6075,
+    #[msg("No season is active")]
+    SeasonNotActive = 6076,
+    #[msg("Season mint accounts required while a season is active")]
+    MissingSeasonAccounts = 6077,
+    #[msg("Account must be migrated before use")]
+    AccountNeedsMigration = 6078,
+    #[msg("Leaderboard does not belong to the active season")]
+    LeaderboardMismatch = 6079,
+    #[msg("Invalid reward distribution")]
+    InvalidDistribution = 6080,
+    #[msg("Merkle proof does not match the distribution root")]
+    InvalidMerkleProof = 6081,
+    #[msg("Distribution already claimed")]
+    DistributionAlreadyClaimed = 6082,
+    #[msg("Distribution has expired")]
+    DistributionExpired = 6083,
+    #[msg("Distribution has not expired")]
+    DistributionNotExpired = 6084,
+    #[msg("Guild id must be a non-empty lowercase slug")]
+    InvalidGuildId = 6085,
+    #[msg("Guild is full")]
+    GuildFull = 6086,
+    #[msg("Learner is already in a guild")]
+    AlreadyInGuild = 6087,
+    #[msg("Learner is not in this guild")]
+    NotInGuild = 6088,
+    #[msg("Guild switch cooldown has not elapsed")]
+    GuildSwitchCooldown = 6089,
+    #[msg("Guild leader cannot leave while other members remain")]
+    GuildLeaderCannotLeave = 6090,
+    #[msg("Guild account required for a guild member")]
+    MissingGuildAccount = 6091,
+    #[msg("Guild membership account does not match the recipient")]
+    InvalidGuildMembership = 6092,
+    #[msg("Mentor role is not active")]
+    MentorNotActive = 6093,
+    #[msg("Mentor is not assigned to this course")]
+    MentorNotAssigned = 6094,
+    #[msg("Mentor review window cap exceeded")]
+    MentorWindowExceeded = 6095,
+    #[msg("Lesson is not mentor-graded")]
+    LessonNotMentorGraded = 6096,
+    #[msg("Mentor-graded lessons must be completed by a mentor")]
+    LessonRequiresMentor = 6097,
+    #[msg("Invalid mentor course list")]
+    InvalidMentorCourses = 6098,
+    #[msg("Mentor XP accounts required for a review reward")]
+    MissingMentorRewardAccounts = 6099,
+    #[msg("Backend signer is already active")]
+    BackendSignerAlreadyActive = 6101,
+    #[msg("Backend signer role account required to migrate the config")]
+    MissingBackendSignerRole = 6102,
+    #[msg("Learner has already completed this course")]
+    CourseAlreadyCompleted = 6103,
+    #[msg("Account cannot be moved to its hashed-id address")]
+    AccountNotRehomable = 6104,
+    #[msg("SOL commitment stakes need an enrollment account to hold them")]
+    CompressedNativeCommitment = 6105,
 }
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{
+        AddBackendSignerParams, BackendSignerRole, Config, MAX_BACKEND_SIGNERS,
+        MAX_BACKEND_SIGNER_LABEL_LEN,
+    },
+};
+
+pub fn add_backend_signer(
+    ctx: Context<AddBackendSigner>,
+    params: AddBackendSignerParams,
+) -> Result<()> {
+    require!(
+        params.label.len() <= MAX_BACKEND_SIGNER_LABEL_LEN,
+        AcademyError::InvalidMetadata
+    );
+    require!(
+        params.valid_from < params.valid_until,
+        AcademyError::InvalidValidityWindow
+    );
+
+    let config = &mut ctx.accounts.config;
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        config.authority,
+        AcademyError::Unauthorized
+    );
+    require!(
+        config.backend_signer_count < MAX_BACKEND_SIGNERS,
+        AcademyError::BackendSignerSetFull
+    );
+    config.backend_signer_count += 1;
+
+    // A previously removed signer is reactivated in place, keeping its
+    // lifetime `total_xp_minted`.
+    let signer_role = &mut ctx.accounts.backend_signer_role;
+    if signer_role.signer == Pubkey::default() {
+        signer_role.set_inner(BackendSignerRole::new(
+            params.signer,
+            params.label,
+            params.valid_from,
+            params.valid_until,
+            Clock::get()?.unix_timestamp,
+            ctx.bumps.backend_signer_role,
+        ));
+    } else {
+        require!(
+            !signer_role.is_active,
+            AcademyError::BackendSignerAlreadyActive
+        );
+        signer_role.label = params.label;
+        signer_role.valid_from = params.valid_from;
+        signer_role.valid_until = params.valid_until;
+        signer_role.is_active = true;
+    }
+
+    emit!(BackendSignerAdded {
+        signer: signer_role.signer,
+        label: signer_role.label.clone(),
+        valid_from: signer_role.valid_from,
+        valid_until: signer_role.valid_until,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: AddBackendSignerParams)]
+pub struct AddBackendSigner<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + BackendSignerRole::LEN,
+        seeds = [b"backend_signer", params.signer.as_ref()],
+        bump
+    )]
+    pub backend_signer_role: Account<'info, BackendSignerRole>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct BackendSignerAdded {
+    pub signer: Pubkey,
+    pub label: String,
+    pub valid_from: i64,
+    pub valid_until: i64,
+}
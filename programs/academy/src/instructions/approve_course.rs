@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{Course, CourseStatus, CourseSubmission, ReviewerRole},
+    utils::id_seed,
+};
+
+pub fn approve_course(ctx: Context<ApproveCourse>, reason_hash: [u8; 32]) -> Result<()> {
+    let course = &mut ctx.accounts.course;
+    require!(
+        course.status == CourseStatus::Pending,
+        AcademyError::CourseNotPending
+    );
+
+    course.status = CourseStatus::Approved;
+    course.is_active = true;
+
+    emit!(CourseApproved {
+        course_id: course.course_id.clone(),
+        reviewer: ctx.accounts.reviewer.key(),
+        reason_hash,
+        deposit_returned: ctx.accounts.submission.deposit,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveCourse<'info> {
+    #[account(mut, seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
+    pub course: Account<'info, Course>,
+    #[account(
+        mut,
+        close = submitter,
+        seeds = [b"submission", course.key().as_ref()],
+        bump = submission.bump,
+        constraint = submission.submitter == submitter.key() @ AcademyError::Unauthorized,
+    )]
+    pub submission: Account<'info, CourseSubmission>,
+    /// CHECK: Verified against `submission.submitter`; receives the deposit and rent.
+    #[account(mut)]
+    pub submitter: UncheckedAccount<'info>,
+    #[account(seeds = [b"reviewer", reviewer.key().as_ref()], bump = reviewer_role.bump)]
+    pub reviewer_role: Account<'info, ReviewerRole>,
+    pub reviewer: Signer<'info>,
+}
+
+#[event]
+pub struct CourseApproved {
+    pub course_id: String,
+    pub reviewer: Pubkey,
+    pub reason_hash: [u8; 32],
+    pub deposit_returned: u64,
+}
@@ -3,47 +3,64 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
     errors::AcademyError,
-    state::{AchievementReceipt, AchievementType, Config, I80F48, MinterRole},
-    utils::{checked_add_i80, mint_xp},
+    state::{
+        AchievementReceipt, AchievementType, Config, Guild, Leaderboard, MinterRole,
+        CURRENT_ACCOUNT_VERSION, I80F48, LIFETIME_LEADERBOARD,
+    },
+    utils::{checked_add_i80, id_seed, mint_xp, record_guild_xp, record_leaderboards},
 };
 
 pub fn award_achievement(ctx: Context<AwardAchievement>) -> Result<()> {
-    let achievement_type = &mut ctx.accounts.achievement_type;
+    process_award_achievement(
+        &mut ctx.accounts.award,
+        &mut ctx.accounts.achievement_receipt,
+        ctx.bumps.achievement_receipt,
+    )
+}
+
+// Shared with the compressed build's `award_achievement`; the caller supplies
+// the newly created receipt, which can only exist once per recipient.
+pub(crate) fn process_award_achievement(
+    accounts: &mut AchievementAward,
+    receipt: &mut AchievementReceipt,
+    receipt_bump: u8,
+) -> Result<()> {
+    let achievement_type = &mut accounts.achievement_type;
 
     require!(achievement_type.is_active, AcademyError::AchievementNotActive);
     require!(
         achievement_type.current_supply < achievement_type.max_supply,
         AcademyError::AchievementSupplyExhausted
     );
-    require!(ctx.accounts.minter_role.is_active, AcademyError::MinterNotActive);
+    require!(accounts.minter_role.is_active, AcademyError::MinterNotActive);
 
     require_keys_eq!(
-        ctx.accounts.collection.key(),
+        accounts.collection.key(),
         achievement_type.collection,
         AcademyError::InvalidMetadata
     );
     require_keys_eq!(
-        ctx.accounts.xp_mint.key(),
-        ctx.accounts.config.xp_mint,
+        accounts.xp_mint.key(),
+        accounts.config.xp_mint,
         AcademyError::MintMismatch
     );
     require_keys_eq!(
-        ctx.accounts.recipient_token_account.owner,
-        ctx.accounts.recipient.key(),
+        accounts.recipient_token_account.owner,
+        accounts.recipient.key(),
         AcademyError::InvalidTokenAccount
     );
     require_keys_eq!(
-        ctx.accounts.recipient_token_account.mint,
-        ctx.accounts.xp_mint.key(),
+        accounts.recipient_token_account.mint,
+        accounts.xp_mint.key(),
         AcademyError::MintMismatch
     );
 
-    let receipt = &mut ctx.accounts.achievement_receipt;
     receipt.achievement_id = achievement_type.achievement_id.clone();
-    receipt.recipient = ctx.accounts.recipient.key();
-    receipt.asset = ctx.accounts.asset.key();
+    receipt.recipient = accounts.recipient.key();
+    receipt.asset = accounts.asset.key();
     receipt.awarded_at = Clock::get()?.unix_timestamp;
-    receipt.bump = ctx.bumps.achievement_receipt;
+    receipt.bump = receipt_bump;
+    receipt.version = CURRENT_ACCOUNT_VERSION;
 
     achievement_type.current_supply = achievement_type
         .current_supply
@@ -51,16 +68,37 @@ pub fn award_achievement(ctx: Context<AwardAchievement>) -> Result<()> {
         .ok_or_else(|| error!(AcademyError::Overflow))?;
 
     let xp_reward = achievement_type.xp_reward as u64;
+    accounts.minter_role.consume_window(
+        I80F48::from_u64(xp_reward),
+        Clock::get()?.unix_timestamp,
+    )?;
     mint_xp(
-        &ctx.accounts.config,
-        &ctx.accounts.xp_mint,
-        &ctx.accounts.recipient_token_account,
-        &ctx.accounts.token_program,
+        &accounts.config,
+        &accounts.xp_mint,
+        &accounts.recipient_token_account,
+        accounts
+            .season_mint
+            .as_ref()
+            .zip(accounts.recipient_season_account.as_ref()),
+        &accounts.token_program,
+        xp_reward,
+    )?;
+    record_leaderboards(
+        &accounts.config,
+        &mut accounts.leaderboard,
+        accounts.season_leaderboard.as_deref_mut(),
+        &accounts.recipient_token_account,
+        accounts.recipient_season_account.as_ref(),
+        xp_reward,
+    )?;
+    record_guild_xp(
+        &accounts.guild_membership,
+        accounts.guild.as_deref_mut(),
         xp_reward,
     )?;
 
-    ctx.accounts.minter_role.total_xp_minted = checked_add_i80(
-        ctx.accounts.minter_role.total_xp_minted,
+    accounts.minter_role.total_xp_minted = checked_add_i80(
+        accounts.minter_role.total_xp_minted,
         I80F48::from_u64(xp_reward),
     )?;
 
@@ -78,26 +116,34 @@ pub fn award_achievement(ctx: Context<AwardAchievement>) -> Result<()> {
 
 #[derive(Accounts)]
 pub struct AwardAchievement<'info> {
-    #[account(seeds = [b"config"], bump = config.bump)]
-    pub config: Account<'info, Config>,
-    #[account(
-        mut,
-        seeds = [b"achievement", achievement_type.achievement_id.as_bytes()],
-        bump = achievement_type.bump
-    )]
-    pub achievement_type: Account<'info, AchievementType>,
+    pub award: AchievementAward<'info>,
     #[account(
         init,
         payer = payer,
         space = 8 + AchievementReceipt::LEN,
         seeds = [
             b"achievement_receipt",
-            achievement_type.achievement_id.as_bytes(),
-            recipient.key().as_ref()
+            &id_seed(&award.achievement_type.achievement_id)[..],
+            award.recipient.key().as_ref()
         ],
         bump
     )]
     pub achievement_receipt: Account<'info, AchievementReceipt>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AchievementAward<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"achievement", &id_seed(&achievement_type.achievement_id)[..]],
+        bump = achievement_type.bump
+    )]
+    pub achievement_type: Account<'info, AchievementType>,
     #[account(
         mut,
         seeds = [b"minter", minter.key().as_ref()],
@@ -116,12 +162,30 @@ pub struct AwardAchievement<'info> {
     #[account(mut)]
     pub xp_mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
-    pub payer: Signer<'info>,
+    pub season_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub recipient_season_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &LIFETIME_LEADERBOARD.to_le_bytes()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &config.current_season.to_le_bytes()],
+        bump = season_leaderboard.bump
+    )]
+    pub season_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    /// CHECK: The recipient's `GuildMembership` PDA; may not exist.
+    #[account(seeds = [b"guild_membership", recipient_token_account.owner.as_ref()], bump)]
+    pub guild_membership: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub guild: Option<Box<Account<'info, Guild>>>,
     pub minter: Signer<'info>,
     /// CHECK: Metaplex Core program account passed through to integration CPI.
     pub mpl_core_program: UncheckedAccount<'info>,
     pub token_program: Interface<'info, TokenInterface>,
-    pub system_program: Program<'info, System>,
 }
 
 #[event]
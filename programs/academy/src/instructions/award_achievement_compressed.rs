@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use light_sdk::{
+    account::sha::LightAccount,
+    address::v1::derive_address,
+    cpi::{CpiAccounts, CpiInputs},
+    instruction::{PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::{
+    instructions::award_achievement::{process_award_achievement, AchievementAward},
+    state::AchievementReceipt,
+    utils::id_seed,
+    LIGHT_CPI_SIGNER,
+};
+
+// The receipt address is derived from the same seeds as the PDA, so the
+// address tree refuses a second award of the same achievement.
+pub fn award_achievement_compressed<'info>(
+    ctx: Context<'_, '_, '_, 'info, AwardAchievementCompressed<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+) -> Result<()> {
+    let cpi_accounts = CpiAccounts::new(
+        ctx.accounts.payer.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+    let address_tree = address_tree_info
+        .get_tree_pubkey(&cpi_accounts)
+        .map_err(ProgramError::from)?;
+    let (address, address_seed) = derive_address(
+        &[
+            b"achievement_receipt",
+            &id_seed(&ctx.accounts.award.achievement_type.achievement_id)[..],
+            ctx.accounts.award.recipient.key().as_ref(),
+        ],
+        &address_tree,
+        &crate::ID,
+    );
+
+    let mut receipt = LightAccount::<'_, AchievementReceipt>::new_init(
+        &crate::ID,
+        Some(address),
+        output_state_tree_index,
+    );
+    process_award_achievement(&mut ctx.accounts.award, &mut receipt, 0)?;
+
+    CpiInputs::new_with_address(
+        proof,
+        vec![receipt.to_account_info().map_err(ProgramError::from)?],
+        vec![address_tree_info.into_new_address_params_packed(address_seed)],
+    )
+    .invoke_light_system_program(cpi_accounts)
+    .map_err(ProgramError::from)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AwardAchievementCompressed<'info> {
+    pub award: AchievementAward<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
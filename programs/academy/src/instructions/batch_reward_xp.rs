@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    errors::AcademyError,
+    instructions::reward_xp::XpRewarded,
+    state::{Config, Guild, I80F48, Leaderboard, MinterRole, LIFETIME_LEADERBOARD},
+    utils::{checked_add_i80, i80_to_u32, mint_xp, record_guild_xp, record_leaderboards},
+};
+
+pub fn batch_reward_xp<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchRewardXp<'info>>,
+    amounts: Vec<I80F48>,
+    reason: String,
+) -> Result<()> {
+    require!(ctx.accounts.minter_role.is_active, AcademyError::MinterNotActive);
+    ctx.accounts.minter_role.require_fixed_point()?;
+    require!(!amounts.is_empty(), AcademyError::InvalidAmount);
+    // Each recipient passes its lifetime account, its season account while a
+    // season runs, its guild membership PDA, and its guild (or this program's
+    // id when it has none).
+    let season_slots = usize::from(ctx.accounts.config.season_active);
+    let accounts_per_recipient = 3 + season_slots;
+    require!(
+        amounts.len() * accounts_per_recipient == ctx.remaining_accounts.len(),
+        AcademyError::BatchLengthMismatch
+    );
+
+    require_keys_eq!(
+        ctx.accounts.xp_mint.key(),
+        ctx.accounts.config.xp_mint,
+        AcademyError::MintMismatch
+    );
+
+    // As in `reward_xp`, the role is charged the truncated amounts minted.
+    let max_xp_per_call = ctx.accounts.minter_role.max_xp_per_call;
+    let mut minted_amounts = Vec::with_capacity(amounts.len());
+    let mut total = I80F48::ZERO;
+    for amount in amounts.iter() {
+        require!(amount.value > 0, AcademyError::InvalidAmount);
+        require!(
+            amount.value <= max_xp_per_call.value,
+            AcademyError::MinterAmountExceeded
+        );
+        let minted = amount.to_token_amount(ctx.accounts.xp_mint.decimals)?;
+        require!(minted > 0, AcademyError::InvalidAmount);
+        total = checked_add_i80(total, I80F48::from_u64(minted))?;
+        minted_amounts.push(minted);
+    }
+    ctx.accounts
+        .minter_role
+        .consume_window(total, Clock::get()?.unix_timestamp)?;
+
+    for (recipient_infos, minted) in ctx
+        .remaining_accounts
+        .chunks(accounts_per_recipient)
+        .zip(minted_amounts)
+    {
+        let recipient_info = &recipient_infos[0];
+        require!(recipient_info.is_writable, AcademyError::InvalidTokenAccount);
+        let recipient_token_account =
+            InterfaceAccount::<'info, TokenAccount>::try_from(recipient_info)?;
+        require_keys_eq!(
+            recipient_token_account.mint,
+            ctx.accounts.xp_mint.key(),
+            AcademyError::MintMismatch
+        );
+        let recipient_season_account = match season_slots {
+            0 => None,
+            _ => {
+                let season_info = &recipient_infos[1];
+                require!(season_info.is_writable, AcademyError::InvalidTokenAccount);
+                Some(InterfaceAccount::<'info, TokenAccount>::try_from(season_info)?)
+            }
+        };
+        let guild_membership_info = &recipient_infos[1 + season_slots];
+        let (guild_membership, _) = Pubkey::find_program_address(
+            &[b"guild_membership", recipient_token_account.owner.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            guild_membership_info.key(),
+            guild_membership,
+            AcademyError::InvalidGuildMembership
+        );
+        let guild_info = &recipient_infos[2 + season_slots];
+        let mut guild = match guild_info.key() {
+            key if key == crate::ID => None,
+            _ => {
+                require!(guild_info.is_writable, AcademyError::MissingGuildAccount);
+                Some(Account::<'info, Guild>::try_from(guild_info)?)
+            }
+        };
+
+        mint_xp(
+            &ctx.accounts.config,
+            &ctx.accounts.xp_mint,
+            &recipient_token_account,
+            ctx.accounts
+                .season_mint
+                .as_ref()
+                .zip(recipient_season_account.as_ref()),
+            &ctx.accounts.token_program,
+            minted,
+        )?;
+        record_leaderboards(
+            &ctx.accounts.config,
+            &mut ctx.accounts.leaderboard,
+            ctx.accounts.season_leaderboard.as_deref_mut(),
+            &recipient_token_account,
+            recipient_season_account.as_ref(),
+            minted,
+        )?;
+        record_guild_xp(guild_membership_info, guild.as_mut(), minted)?;
+        if let Some(guild) = guild {
+            guild.exit(&crate::ID)?;
+        }
+
+        emit!(XpRewarded {
+            recipient: recipient_token_account.owner,
+            amount: i80_to_u32(I80F48::from_u64(minted))?,
+            reason: reason.clone(),
+        });
+    }
+
+    ctx.accounts.minter_role.total_xp_minted =
+        checked_add_i80(ctx.accounts.minter_role.total_xp_minted, total)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BatchRewardXp<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"minter", minter.key().as_ref()],
+        bump = minter_role.bump,
+        constraint = minter_role.minter == minter.key() @ AcademyError::MinterRoleMismatch,
+    )]
+    pub minter_role: Account<'info, MinterRole>,
+    #[account(mut)]
+    pub xp_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub season_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &LIFETIME_LEADERBOARD.to_le_bytes()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &config.current_season.to_le_bytes()],
+        bump = season_leaderboard.bump
+    )]
+    pub season_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    pub minter: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
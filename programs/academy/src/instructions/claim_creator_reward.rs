@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    errors::AcademyError,
+    state::{Config, CreatorVault, Guild, Leaderboard, LIFETIME_LEADERBOARD},
+    utils::{mint_xp, record_guild_xp, record_leaderboards},
+};
+
+pub fn claim_creator_reward(ctx: Context<ClaimCreatorReward>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.xp_mint.key(),
+        ctx.accounts.config.xp_mint,
+        AcademyError::MintMismatch
+    );
+    require_keys_eq!(
+        ctx.accounts.recipient_token_account.owner,
+        ctx.accounts.recipient.key(),
+        AcademyError::InvalidTokenAccount
+    );
+    require_keys_eq!(
+        ctx.accounts.recipient_token_account.mint,
+        ctx.accounts.xp_mint.key(),
+        AcademyError::MintMismatch
+    );
+
+    let recipient = ctx.accounts.recipient.key();
+    let vault = &mut ctx.accounts.creator_vault;
+    let position = vault
+        .shares
+        .iter()
+        .position(|share| share.recipient == recipient)
+        .ok_or_else(|| error!(AcademyError::CreatorShareNotFound))?;
+    let share = &mut vault.shares[position];
+
+    let amount = share.pending_xp;
+    require!(amount > 0, AcademyError::NothingToClaim);
+    share.pending_xp = 0;
+    share.claimed_xp = share
+        .claimed_xp
+        .checked_add(amount)
+        .ok_or_else(|| error!(AcademyError::Overflow))?;
+    // A co-creator removed by `set_creator_splits` was only kept to claim.
+    if share.bps == 0 {
+        vault.shares.remove(position);
+    }
+
+    mint_xp(
+        &ctx.accounts.config,
+        &ctx.accounts.xp_mint,
+        &ctx.accounts.recipient_token_account,
+        ctx.accounts
+            .season_mint
+            .as_ref()
+            .zip(ctx.accounts.recipient_season_account.as_ref()),
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+    record_leaderboards(
+        &ctx.accounts.config,
+        &mut ctx.accounts.leaderboard,
+        ctx.accounts.season_leaderboard.as_deref_mut(),
+        &ctx.accounts.recipient_token_account,
+        ctx.accounts.recipient_season_account.as_ref(),
+        amount,
+    )?;
+    record_guild_xp(
+        &ctx.accounts.guild_membership,
+        ctx.accounts.guild.as_deref_mut(),
+        amount,
+    )?;
+
+    emit!(CreatorRewardClaimed {
+        course: vault.course,
+        recipient,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimCreatorReward<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"creator_vault", creator_vault.course.as_ref()],
+        bump = creator_vault.bump
+    )]
+    pub creator_vault: Account<'info, CreatorVault>,
+    pub recipient: Signer<'info>,
+    #[account(mut)]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub xp_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub season_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub recipient_season_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &LIFETIME_LEADERBOARD.to_le_bytes()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &config.current_season.to_le_bytes()],
+        bump = season_leaderboard.bump
+    )]
+    pub season_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    /// CHECK: The recipient's `GuildMembership` PDA; may not exist.
+    #[account(seeds = [b"guild_membership", recipient_token_account.owner.as_ref()], bump)]
+    pub guild_membership: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub guild: Option<Box<Account<'info, Guild>>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct CreatorRewardClaimed {
+    pub course: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
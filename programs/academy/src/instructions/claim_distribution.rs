@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    errors::AcademyError,
+    state::RewardDistribution,
+    utils::{distribution_leaf, transfer_from_vault, verify_merkle_proof},
+};
+
+pub fn claim_distribution(
+    ctx: Context<ClaimDistribution>,
+    index: u32,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let distribution = &ctx.accounts.distribution;
+    require!(
+        Clock::get()?.unix_timestamp < distribution.expires_at,
+        AcademyError::DistributionExpired
+    );
+    require!(
+        index < distribution.num_recipients,
+        AcademyError::InvalidDistribution
+    );
+    require!(
+        !distribution.is_claimed(index),
+        AcademyError::DistributionAlreadyClaimed
+    );
+
+    let learner = ctx.accounts.learner.key();
+    require!(
+        verify_merkle_proof(
+            &proof,
+            &distribution.merkle_root,
+            distribution_leaf(index, &learner, amount),
+        ),
+        AcademyError::InvalidMerkleProof
+    );
+
+    let claimed_amount = distribution
+        .claimed_amount
+        .checked_add(amount)
+        .ok_or_else(|| error!(AcademyError::Overflow))?;
+    require!(
+        claimed_amount <= distribution.total_amount,
+        AcademyError::InvalidDistribution
+    );
+
+    let distribution_key = distribution.key();
+    let vault_bump = [ctx.bumps.distribution_vault];
+    let vault_seeds: &[&[u8]] = &[
+        b"distribution_vault",
+        distribution_key.as_ref(),
+        &vault_bump,
+    ];
+    transfer_from_vault(
+        &ctx.accounts.distribution_vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.learner_token_account,
+        &ctx.accounts.token_program,
+        vault_seeds,
+        amount,
+    )?;
+
+    let distribution = &mut ctx.accounts.distribution;
+    distribution.set_claimed(index);
+    distribution.claimed_amount = claimed_amount;
+    distribution.claimed_count = distribution
+        .claimed_count
+        .checked_add(1)
+        .ok_or_else(|| error!(AcademyError::Overflow))?;
+
+    emit!(DistributionClaimed {
+        season: distribution.season,
+        learner,
+        index,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimDistribution<'info> {
+    #[account(
+        mut,
+        seeds = [b"distribution".as_ref(), &distribution.season.to_le_bytes()],
+        bump = distribution.bump
+    )]
+    pub distribution: Box<Account<'info, RewardDistribution>>,
+    #[account(mut, seeds = [b"distribution_vault", distribution.key().as_ref()], bump)]
+    pub distribution_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = distribution.mint @ AcademyError::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, token::mint = mint, token::authority = learner)]
+    pub learner_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub learner: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct DistributionClaimed {
+    pub season: u32,
+    pub learner: Pubkey,
+    pub index: u32,
+    pub amount: u64,
+}
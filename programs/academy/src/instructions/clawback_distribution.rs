@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    errors::AcademyError,
+    state::{Config, RewardDistribution},
+    utils::transfer_from_vault,
+};
+
+// Returns whatever is left in the vault to the treasury once claims close.
+pub fn clawback_distribution(ctx: Context<ClawbackDistribution>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        ctx.accounts.config.authority,
+        AcademyError::Unauthorized
+    );
+    require_keys_eq!(
+        ctx.accounts.treasury_token_account.owner,
+        ctx.accounts.config.treasury,
+        AcademyError::TreasuryMismatch
+    );
+    let distribution = &ctx.accounts.distribution;
+    require!(
+        Clock::get()?.unix_timestamp >= distribution.expires_at,
+        AcademyError::DistributionNotExpired
+    );
+
+    let amount = ctx.accounts.distribution_vault.amount;
+    require!(amount > 0, AcademyError::NothingToClaim);
+
+    let distribution_key = distribution.key();
+    let vault_bump = [ctx.bumps.distribution_vault];
+    let vault_seeds: &[&[u8]] = &[
+        b"distribution_vault",
+        distribution_key.as_ref(),
+        &vault_bump,
+    ];
+    transfer_from_vault(
+        &ctx.accounts.distribution_vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.treasury_token_account,
+        &ctx.accounts.token_program,
+        vault_seeds,
+        amount,
+    )?;
+
+    emit!(DistributionClawedBack {
+        season: distribution.season,
+        amount,
+        unclaimed_recipients: distribution.num_recipients - distribution.claimed_count,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClawbackDistribution<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        seeds = [b"distribution".as_ref(), &distribution.season.to_le_bytes()],
+        bump = distribution.bump
+    )]
+    pub distribution: Box<Account<'info, RewardDistribution>>,
+    #[account(mut, seeds = [b"distribution_vault", distribution.key().as_ref()], bump)]
+    pub distribution_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = distribution.mint @ AcademyError::MintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, token::mint = mint)]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct DistributionClawedBack {
+    pub season: u32,
+    pub amount: u64,
+    pub unclaimed_recipients: u32,
+}
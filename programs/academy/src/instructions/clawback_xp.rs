@@ -0,0 +1,230 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    self, Burn, FreezeAccount, Mint, ThawAccount, TokenAccount, TokenInterface,
+};
+
+use crate::{
+    errors::AcademyError,
+    state::{
+        BackendSignerRole, Config, Guild, Leaderboard, MinterRole, I80F48, LIFETIME_LEADERBOARD,
+    },
+    utils::revoke_guild_xp,
+};
+
+pub fn clawback_xp(ctx: Context<ClawbackXp>, amount: u64, reason_code: u8) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        ctx.accounts.config.authority,
+        AcademyError::Unauthorized
+    );
+    require_keys_eq!(
+        ctx.accounts.xp_mint.key(),
+        ctx.accounts.config.xp_mint,
+        AcademyError::MintMismatch
+    );
+    require!(amount > 0, AcademyError::InvalidAmount);
+
+    let signer_seeds: &[&[u8]] = &[b"config", &[ctx.accounts.config.bump]];
+    burn_as_delegate(
+        &ctx.accounts.config,
+        &ctx.accounts.xp_mint,
+        &ctx.accounts.learner_token_account,
+        &ctx.accounts.token_program,
+        signer_seeds,
+        amount,
+    )?;
+
+    // Season XP was minted alongside, so the season share of the clawback
+    // goes too; lifetime XP earned in earlier seasons has no season balance.
+    let mut season_amount = 0;
+    if ctx.accounts.config.season_active {
+        let (Some(season_mint), Some(learner_season_account)) = (
+            ctx.accounts.season_mint.as_ref(),
+            ctx.accounts.learner_season_account.as_ref(),
+        ) else {
+            return err!(AcademyError::MissingSeasonAccounts);
+        };
+        require_keys_eq!(
+            season_mint.key(),
+            ctx.accounts.config.season_mint,
+            AcademyError::MintMismatch
+        );
+        require_keys_eq!(
+            learner_season_account.owner,
+            ctx.accounts.learner_token_account.owner,
+            AcademyError::InvalidTokenAccount
+        );
+        season_amount = amount.min(learner_season_account.amount);
+        if season_amount > 0 {
+            burn_as_delegate(
+                &ctx.accounts.config,
+                season_mint,
+                learner_season_account,
+                &ctx.accounts.token_program,
+                signer_seeds,
+                season_amount,
+            )?;
+        }
+    }
+
+    let learner = ctx.accounts.learner_token_account.owner;
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.leaderboard.deduct(learner, amount, now);
+    if season_amount > 0 {
+        let season_leaderboard = ctx
+            .accounts
+            .season_leaderboard
+            .as_mut()
+            .ok_or_else(|| error!(AcademyError::MissingSeasonAccounts))?;
+        require!(
+            season_leaderboard.season == ctx.accounts.config.current_season,
+            AcademyError::LeaderboardMismatch
+        );
+        season_leaderboard.deduct(learner, season_amount, now);
+    }
+    revoke_guild_xp(
+        &ctx.accounts.guild_membership,
+        ctx.accounts.guild.as_deref_mut(),
+        amount,
+    )?;
+
+    // The issuing role's totals are corrected too.
+    let clawed_back = I80F48::from_u64(amount);
+    if let Some(minter_role) = ctx.accounts.minter_role.as_mut() {
+        minter_role.require_fixed_point()?;
+        require!(
+            minter_role.total_xp_minted.value >= clawed_back.value,
+            AcademyError::ClawbackExceedsMinted
+        );
+        minter_role.total_xp_minted = minter_role.total_xp_minted.checked_sub(clawed_back)?;
+    }
+    if let Some(backend_signer_role) = ctx.accounts.backend_signer_role.as_mut() {
+        backend_signer_role.require_fixed_point()?;
+        require!(
+            backend_signer_role.total_xp_minted.value >= clawed_back.value,
+            AcademyError::ClawbackExceedsMinted
+        );
+        backend_signer_role.total_xp_minted =
+            backend_signer_role.total_xp_minted.checked_sub(clawed_back)?;
+    }
+
+    emit!(XpClawedBack {
+        learner,
+        token_account: ctx.accounts.learner_token_account.key(),
+        amount,
+        season_amount,
+        reason_code,
+    });
+
+    Ok(())
+}
+
+// Frozen accounts can't be burned from, even by the permanent delegate, so a
+// frozen account is thawed for the burn and frozen again afterwards.
+fn burn_as_delegate<'info>(
+    config: &Account<'info, Config>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_account: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    signer_seeds: &[&[u8]],
+    amount: u64,
+) -> Result<()> {
+    let token_program = token_program.to_account_info();
+    let token_account_info = token_account.to_account_info();
+    let mint = mint.to_account_info();
+    let config = config.to_account_info();
+    let signer: &[&[&[u8]]] = &[signer_seeds];
+
+    let was_frozen = token_account.is_frozen();
+    if was_frozen {
+        token_interface::thaw_account(CpiContext::new_with_signer(
+            token_program.clone(),
+            ThawAccount {
+                account: token_account_info.clone(),
+                mint: mint.clone(),
+                authority: config.clone(),
+            },
+            signer,
+        ))?;
+    }
+
+    token_interface::burn(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            Burn {
+                mint: mint.clone(),
+                from: token_account_info.clone(),
+                authority: config.clone(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+
+    if was_frozen {
+        token_interface::freeze_account(CpiContext::new_with_signer(
+            token_program,
+            FreezeAccount {
+                account: token_account_info,
+                mint,
+                authority: config,
+            },
+            signer,
+        ))?;
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClawbackXp<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub xp_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub learner_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub season_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub learner_season_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &LIFETIME_LEADERBOARD.to_le_bytes()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &config.current_season.to_le_bytes()],
+        bump = season_leaderboard.bump
+    )]
+    pub season_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    /// CHECK: The learner's `GuildMembership` PDA; may not exist.
+    #[account(seeds = [b"guild_membership", learner_token_account.owner.as_ref()], bump)]
+    pub guild_membership: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub guild: Option<Box<Account<'info, Guild>>>,
+    #[account(
+        mut,
+        seeds = [b"minter", minter_role.minter.as_ref()],
+        bump = minter_role.bump
+    )]
+    pub minter_role: Option<Account<'info, MinterRole>>,
+    #[account(
+        mut,
+        seeds = [b"backend_signer", backend_signer_role.signer.as_ref()],
+        bump = backend_signer_role.bump
+    )]
+    pub backend_signer_role: Option<Account<'info, BackendSignerRole>>,
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct XpClawedBack {
+    pub learner: Pubkey,
+    pub token_account: Pubkey,
+    pub amount: u64,
+    pub season_amount: u64,
+    pub reason_code: u8,
+}
@@ -1,11 +1,15 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::AcademyError, state::{Course, Enrollment}};
+use crate::{
+    errors::AcademyError,
+    state::{Cohort, Course, Enrollment, EnrollmentArchive},
+    utils::{id_seed, release_cohort_seat},
+};
 
 const INCOMPLETE_CLOSE_COOLDOWN_SECS: i64 = 24 * 60 * 60;
 
 pub fn close_enrollment(ctx: Context<CloseEnrollment>) -> Result<()> {
-    let course = &ctx.accounts.course;
+    let course = &mut ctx.accounts.course;
     let enrollment = &ctx.accounts.enrollment;
 
     require!(enrollment.course_id == course.course_id, AcademyError::InvalidCourseId);
@@ -14,9 +18,13 @@ pub fn close_enrollment(ctx: Context<CloseEnrollment>) -> Result<()> {
         ctx.accounts.learner.key(),
         AcademyError::Unauthorized
     );
+    require!(
+        enrollment.commitment_amount == 0,
+        AcademyError::CommitmentOutstanding
+    );
 
+    let now = Clock::get()?.unix_timestamp;
     if enrollment.completed_at.is_none() {
-        let now = Clock::get()?.unix_timestamp;
         let elapsed = now.saturating_sub(enrollment.enrolled_at);
         require!(
             elapsed >= INCOMPLETE_CLOSE_COOLDOWN_SECS,
@@ -24,9 +32,21 @@ pub fn close_enrollment(ctx: Context<CloseEnrollment>) -> Result<()> {
         );
     }
 
+    // Walking away from a paid course forfeits the fee to the creator/treasury;
+    // learners who want their money back use `refund_enrollment` instead.
+    if enrollment.escrowed_amount > 0 {
+        course.release_revenue(enrollment.escrowed_amount)?;
+    }
+
+    release_cohort_seat(enrollment, ctx.accounts.cohort.as_mut())?;
+
+    let archive = &mut ctx.accounts.enrollment_archive;
+    archive.record_attempt(enrollment, ctx.bumps.enrollment_archive, now)?;
+
     emit!(EnrollmentClosed {
         learner: ctx.accounts.learner.key(),
         course_id: enrollment.course_id.clone(),
+        attempt_count: archive.attempt_count,
     });
 
     Ok(())
@@ -34,21 +54,37 @@ pub fn close_enrollment(ctx: Context<CloseEnrollment>) -> Result<()> {
 
 #[derive(Accounts)]
 pub struct CloseEnrollment<'info> {
-    #[account(seeds = [b"course", course.course_id.as_bytes()], bump = course.bump)]
+    #[account(mut, seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
     pub course: Account<'info, Course>,
     #[account(
         mut,
         close = learner,
-        seeds = [b"enrollment", enrollment.course_id.as_bytes(), learner.key().as_ref()],
+        seeds = [b"enrollment", &id_seed(&enrollment.course_id)[..], learner.key().as_ref()],
         bump = enrollment.bump
     )]
     pub enrollment: Account<'info, Enrollment>,
+    #[account(
+        init_if_needed,
+        payer = learner,
+        space = 8 + EnrollmentArchive::LEN,
+        seeds = [
+            b"enrollment_archive",
+            &id_seed(&enrollment.course_id)[..],
+            learner.key().as_ref()
+        ],
+        bump
+    )]
+    pub enrollment_archive: Account<'info, EnrollmentArchive>,
+    #[account(mut)]
+    pub cohort: Option<Account<'info, Cohort>>,
     #[account(mut)]
     pub learner: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 #[event]
 pub struct EnrollmentClosed {
     pub learner: Pubkey,
     pub course_id: String,
+    pub attempt_count: u32,
 }
@@ -1,19 +1,55 @@
 use anchor_lang::prelude::*;
-use crate::state::Config;
+use anchor_spl::token_interface::Mint;
 
-pub fn close_season(ctx: Context<CloseSeasonAccounts>) -> Result<()> {
+use crate::{
+    errors::AcademyError,
+    state::{Config, Season},
+};
+
+// The season mint stays in place after closing so its final supply and
+// balances remain readable; only new awards stop landing on it.
+pub fn close_season(ctx: Context<CloseSeason>) -> Result<()> {
     let config = &mut ctx.accounts.config;
-    config.season_closed = true;
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        config.authority,
+        AcademyError::Unauthorized
+    );
+    require!(config.season_active, AcademyError::SeasonNotActive);
+    config.season_active = false;
+
+    let season = &mut ctx.accounts.season;
+    season.closed_at = Clock::get()?.unix_timestamp;
+
+    emit!(SeasonClosed {
+        season: season.season,
+        mint: season.mint,
+        total_xp: ctx.accounts.season_mint.supply,
+        closed_at: season.closed_at,
+    });
 
-    msg!("✅ Season {} closed", config.current_season);
     Ok(())
 }
 
-pub struct CloseSeason;
-
 #[derive(Accounts)]
-pub struct CloseSeasonAccounts<'info> {
-    #[account(mut)]
+pub struct CloseSeason<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
     pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"season".as_ref(), &config.current_season.to_le_bytes()],
+        bump = season.bump
+    )]
+    pub season: Account<'info, Season>,
+    #[account(address = season.mint @ AcademyError::MintMismatch)]
+    pub season_mint: InterfaceAccount<'info, Mint>,
     pub authority: Signer<'info>,
 }
+
+#[event]
+pub struct SeasonClosed {
+    pub season: u32,
+    pub mint: Pubkey,
+    pub total_xp: u64,
+    pub closed_at: i64,
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{CompletionRecord, Course, Enrollment, CURRENT_ACCOUNT_VERSION},
+    utils::id_seed,
+};
+
+pub fn compact_enrollment(ctx: Context<CompactEnrollment>) -> Result<()> {
+    let enrollment = &ctx.accounts.enrollment;
+
+    require!(
+        enrollment.course_id == ctx.accounts.course.course_id,
+        AcademyError::InvalidCourseId
+    );
+    let completed_at = enrollment
+        .completed_at
+        .ok_or_else(|| error!(AcademyError::CourseNotFinalized))?;
+    require!(
+        enrollment.commitment_amount == 0,
+        AcademyError::CommitmentOutstanding
+    );
+
+    let completion_record = &mut ctx.accounts.completion_record;
+    completion_record.course = ctx.accounts.course.key();
+    completion_record.learner = enrollment.learner;
+    completion_record.completed_at = completed_at;
+    completion_record.credential = enrollment.credential_asset;
+    completion_record.bump = ctx.bumps.completion_record;
+    completion_record.version = CURRENT_ACCOUNT_VERSION;
+
+    emit!(EnrollmentCompacted {
+        learner: enrollment.learner,
+        course_id: enrollment.course_id.clone(),
+        completed_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CompactEnrollment<'info> {
+    #[account(seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
+    pub course: Account<'info, Course>,
+    #[account(
+        mut,
+        close = learner,
+        seeds = [b"enrollment", &id_seed(&enrollment.course_id)[..], learner.key().as_ref()],
+        bump = enrollment.bump
+    )]
+    pub enrollment: Account<'info, Enrollment>,
+    #[account(
+        init,
+        payer = learner,
+        space = 8 + CompletionRecord::LEN,
+        seeds = [b"completion", course.key().as_ref(), learner.key().as_ref()],
+        bump
+    )]
+    pub completion_record: Account<'info, CompletionRecord>,
+    #[account(mut)]
+    pub learner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct EnrollmentCompacted {
+    pub learner: Pubkey,
+    pub course_id: String,
+    pub completed_at: i64,
+}
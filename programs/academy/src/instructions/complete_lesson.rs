@@ -3,24 +3,40 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
     errors::AcademyError,
-    state::{Config, Course, Enrollment},
-    utils::mint_xp,
+    state::{
+        BackendSignerRole, Config, Course, Enrollment, Guild, Leaderboard, LIFETIME_LEADERBOARD,
+    },
+    utils::{id_seed, mint_xp, record_guild_xp, record_leaderboards},
 };
 
 pub fn complete_lesson(ctx: Context<CompleteLesson>, lesson_index: u8) -> Result<()> {
-    require_keys_eq!(
-        ctx.accounts.backend_signer.key(),
-        ctx.accounts.config.backend_signer,
-        AcademyError::BackendSignerMismatch
+    process_complete_lesson(
+        &mut ctx.accounts.lesson,
+        &mut ctx.accounts.enrollment,
+        lesson_index,
+    )
+}
+
+// Shared by `complete_lesson`, `complete_lesson_attested` and the compressed
+// build's `complete_lesson`; the caller has already established that
+// `backend_signer_role` vouches for this lesson and supplies the enrollment.
+pub(crate) fn process_complete_lesson(
+    accounts: &mut LessonCompletion,
+    enrollment: &mut Enrollment,
+    lesson_index: u8,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        accounts.backend_signer_role.is_valid_at(now),
+        AcademyError::BackendSignerNotValid
     );
     require_keys_eq!(
-        ctx.accounts.xp_mint.key(),
-        ctx.accounts.config.xp_mint,
+        accounts.xp_mint.key(),
+        accounts.config.xp_mint,
         AcademyError::MintMismatch
     );
 
-    let course = &ctx.accounts.course;
-    let enrollment = &mut ctx.accounts.enrollment;
+    let course = &accounts.course;
 
     require!(course.is_active, AcademyError::CourseNotActive);
     require!(
@@ -29,9 +45,10 @@ pub fn complete_lesson(ctx: Context<CompleteLesson>, lesson_index: u8) -> Result
     );
     require_keys_eq!(
         enrollment.learner,
-        ctx.accounts.learner.key(),
+        accounts.learner.key(),
         AcademyError::Unauthorized
     );
+    require!(!enrollment.is_expired(now), AcademyError::EnrollmentExpired);
     require!(
         lesson_index < course.lesson_count,
         AcademyError::LessonOutOfBounds
@@ -40,33 +57,57 @@ pub fn complete_lesson(ctx: Context<CompleteLesson>, lesson_index: u8) -> Result
         !enrollment.is_lesson_complete(lesson_index),
         AcademyError::LessonAlreadyCompleted
     );
+    require!(
+        !course.is_mentor_graded(lesson_index),
+        AcademyError::LessonRequiresMentor
+    );
 
     require_keys_eq!(
-        ctx.accounts.learner_token_account.owner,
-        ctx.accounts.learner.key(),
+        accounts.learner_token_account.owner,
+        accounts.learner.key(),
         AcademyError::InvalidTokenAccount
     );
     require_keys_eq!(
-        ctx.accounts.learner_token_account.mint,
-        ctx.accounts.xp_mint.key(),
+        accounts.learner_token_account.mint,
+        accounts.xp_mint.key(),
         AcademyError::MintMismatch
     );
 
     enrollment.set_lesson_complete(lesson_index)?;
     mint_xp(
-        &ctx.accounts.config,
-        &ctx.accounts.xp_mint,
-        &ctx.accounts.learner_token_account,
-        &ctx.accounts.token_program,
+        &accounts.config,
+        &accounts.xp_mint,
+        &accounts.learner_token_account,
+        accounts
+            .season_mint
+            .as_ref()
+            .zip(accounts.learner_season_account.as_ref()),
+        &accounts.token_program,
+        course.xp_per_lesson as u64,
+    )?;
+    record_leaderboards(
+        &accounts.config,
+        &mut accounts.leaderboard,
+        accounts.season_leaderboard.as_deref_mut(),
+        &accounts.learner_token_account,
+        accounts.learner_season_account.as_ref(),
         course.xp_per_lesson as u64,
     )?;
+    record_guild_xp(
+        &accounts.guild_membership,
+        accounts.guild.as_deref_mut(),
+        course.xp_per_lesson as u64,
+    )?;
+    accounts
+        .backend_signer_role
+        .record_mint(course.xp_per_lesson as u64)?;
 
     emit!(LessonCompleted {
-        learner: ctx.accounts.learner.key(),
+        learner: accounts.learner.key(),
         course_id: course.course_id.clone(),
         lesson_index,
         xp_earned: course.xp_per_lesson,
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp: now,
     });
 
     Ok(())
@@ -74,23 +115,60 @@ pub fn complete_lesson(ctx: Context<CompleteLesson>, lesson_index: u8) -> Result
 
 #[derive(Accounts)]
 pub struct CompleteLesson<'info> {
-    #[account(seeds = [b"config"], bump = config.bump)]
-    pub config: Account<'info, Config>,
-    #[account(seeds = [b"course", course.course_id.as_bytes()], bump = course.bump)]
-    pub course: Account<'info, Course>,
+    pub lesson: LessonCompletion<'info>,
     #[account(
         mut,
-        seeds = [b"enrollment", enrollment.course_id.as_bytes(), learner.key().as_ref()],
+        seeds = [
+            b"enrollment",
+            &id_seed(&enrollment.course_id)[..],
+            lesson.learner.key().as_ref()
+        ],
         bump = enrollment.bump
     )]
     pub enrollment: Account<'info, Enrollment>,
+    #[account(address = lesson.backend_signer_role.signer @ AcademyError::Unauthorized)]
+    pub backend_signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct LessonCompletion<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
+    pub course: Account<'info, Course>,
     /// CHECK: Learner pubkey used for ownership checks and enrollment seed validation.
     pub learner: UncheckedAccount<'info>,
     #[account(mut)]
     pub learner_token_account: InterfaceAccount<'info, TokenAccount>,
     #[account(mut)]
     pub xp_mint: InterfaceAccount<'info, Mint>,
-    pub backend_signer: Signer<'info>,
+    #[account(mut)]
+    pub season_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub learner_season_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &LIFETIME_LEADERBOARD.to_le_bytes()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &config.current_season.to_le_bytes()],
+        bump = season_leaderboard.bump
+    )]
+    pub season_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    /// CHECK: The recipient's `GuildMembership` PDA; may not exist.
+    #[account(seeds = [b"guild_membership", learner_token_account.owner.as_ref()], bump)]
+    pub guild_membership: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub guild: Option<Box<Account<'info, Guild>>>,
+    #[account(
+        mut,
+        seeds = [b"backend_signer", backend_signer_role.signer.as_ref()],
+        bump = backend_signer_role.bump
+    )]
+    pub backend_signer_role: Account<'info, BackendSignerRole>,
     pub token_program: Interface<'info, TokenInterface>,
 }
 
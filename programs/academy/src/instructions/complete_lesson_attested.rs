@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::ID as INSTRUCTIONS_SYSVAR_ID;
+
+use crate::{
+    errors::AcademyError,
+    instructions::complete_lesson::*,
+    state::{AttestationNonces, Enrollment, CURRENT_ACCOUNT_VERSION},
+    utils::{attestation_message, id_seed, verify_backend_attestation},
+};
+
+pub fn complete_lesson_attested(
+    ctx: Context<CompleteLessonAttested>,
+    lesson_index: u8,
+    nonce: u64,
+    expiry: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(now <= expiry, AcademyError::AttestationExpired);
+
+    let learner = ctx.accounts.learner.key();
+    let message = attestation_message(
+        &ctx.accounts.lesson.config,
+        &learner,
+        &ctx.accounts.lesson.course.key(),
+        lesson_index,
+        nonce,
+        expiry,
+    );
+    verify_backend_attestation(
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.lesson.backend_signer_role.signer,
+        &message,
+    )?;
+
+    let nonces = &mut ctx.accounts.attestation_nonces;
+    if nonces.learner == Pubkey::default() {
+        nonces.learner = learner;
+        nonces.bump = ctx.bumps.attestation_nonces;
+        nonces.version = CURRENT_ACCOUNT_VERSION;
+    }
+    nonces.consume(nonce)?;
+
+    process_complete_lesson(
+        &mut ctx.accounts.lesson,
+        &mut ctx.accounts.enrollment,
+        lesson_index,
+    )
+}
+
+#[derive(Accounts)]
+pub struct CompleteLessonAttested<'info> {
+    pub lesson: LessonCompletion<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"enrollment",
+            &id_seed(&enrollment.course_id)[..],
+            lesson.learner.key().as_ref()
+        ],
+        bump = enrollment.bump
+    )]
+    pub enrollment: Account<'info, Enrollment>,
+    #[account(
+        init_if_needed,
+        payer = learner,
+        space = 8 + AttestationNonces::LEN,
+        seeds = [b"attestation_nonces", learner.key().as_ref()],
+        bump
+    )]
+    pub attestation_nonces: Account<'info, AttestationNonces>,
+    #[account(mut, address = lesson.learner.key() @ AcademyError::Unauthorized)]
+    pub learner: Signer<'info>,
+    /// CHECK: Instructions sysvar, address-checked; read to find the Ed25519 attestation.
+    #[account(address = INSTRUCTIONS_SYSVAR_ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
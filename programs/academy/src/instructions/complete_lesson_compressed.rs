@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use light_sdk::{
+    account::sha::LightAccount,
+    cpi::{CpiAccounts, CpiInputs},
+    instruction::ValidityProof,
+};
+
+use crate::{
+    errors::AcademyError,
+    instructions::complete_lesson::{process_complete_lesson, LessonCompletion},
+    state::{CompressedEnrollment, Enrollment},
+    LIGHT_CPI_SIGNER,
+};
+
+// Only this program can write accounts it owns into the state tree, so a
+// proven enrollment's `learner` and `course_id` are as trustworthy as the
+// PDA seeds they replace; `process_complete_lesson` checks both.
+pub fn complete_lesson_compressed<'info>(
+    ctx: Context<'_, '_, '_, 'info, CompleteLessonCompressed<'info>>,
+    lesson_index: u8,
+    proof: ValidityProof,
+    enrollment: CompressedEnrollment,
+) -> Result<()> {
+    let mut compressed_enrollment = LightAccount::<'_, Enrollment>::new_mut(
+        &crate::ID,
+        &enrollment.meta,
+        enrollment.enrollment,
+    )
+    .map_err(ProgramError::from)?;
+    process_complete_lesson(
+        &mut ctx.accounts.lesson,
+        &mut compressed_enrollment,
+        lesson_index,
+    )?;
+
+    let cpi_accounts = CpiAccounts::new(
+        ctx.accounts.backend_signer.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+    CpiInputs::new(
+        proof,
+        vec![compressed_enrollment
+            .to_account_info()
+            .map_err(ProgramError::from)?],
+    )
+    .invoke_light_system_program(cpi_accounts)
+    .map_err(ProgramError::from)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CompleteLessonCompressed<'info> {
+    pub lesson: LessonCompletion<'info>,
+    // Also pays the light system program's fees.
+    #[account(mut, address = lesson.backend_signer_role.signer @ AcademyError::Unauthorized)]
+    pub backend_signer: Signer<'info>,
+}
@@ -0,0 +1,274 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    errors::AcademyError,
+    state::{
+        Config, Course, Enrollment, Guild, Leaderboard, MentorReview, MentorRole,
+        CURRENT_ACCOUNT_VERSION, LIFETIME_LEADERBOARD,
+    },
+    utils::{id_seed, mint_xp, record_guild_xp, record_leaderboards},
+};
+
+// Mentor sign-off for a mentor-graded lesson. The learner earns the course's
+// usual lesson XP; the mentor earns `review_xp` on top when it is non-zero.
+pub fn complete_lesson_mentor(ctx: Context<CompleteLessonMentor>, lesson_index: u8) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let mentor = ctx.accounts.mentor.key();
+    require!(
+        ctx.accounts.mentor_role.is_active,
+        AcademyError::MentorNotActive
+    );
+    require!(
+        ctx.accounts.mentor_role.covers(&ctx.accounts.course.key()),
+        AcademyError::MentorNotAssigned
+    );
+    require_keys_neq!(
+        mentor,
+        ctx.accounts.learner.key(),
+        AcademyError::Unauthorized
+    );
+    require_keys_eq!(
+        ctx.accounts.xp_mint.key(),
+        ctx.accounts.config.xp_mint,
+        AcademyError::MintMismatch
+    );
+
+    let course = &ctx.accounts.course;
+    let enrollment = &mut ctx.accounts.enrollment;
+
+    require!(course.is_active, AcademyError::CourseNotActive);
+    require!(
+        enrollment.course_id == course.course_id,
+        AcademyError::InvalidCourseId
+    );
+    require_keys_eq!(
+        enrollment.learner,
+        ctx.accounts.learner.key(),
+        AcademyError::Unauthorized
+    );
+    require!(!enrollment.is_expired(now), AcademyError::EnrollmentExpired);
+    require!(
+        lesson_index < course.lesson_count,
+        AcademyError::LessonOutOfBounds
+    );
+    require!(
+        course.is_mentor_graded(lesson_index),
+        AcademyError::LessonNotMentorGraded
+    );
+    require!(
+        !enrollment.is_lesson_complete(lesson_index),
+        AcademyError::LessonAlreadyCompleted
+    );
+
+    require_keys_eq!(
+        ctx.accounts.learner_token_account.owner,
+        ctx.accounts.learner.key(),
+        AcademyError::InvalidTokenAccount
+    );
+    require_keys_eq!(
+        ctx.accounts.learner_token_account.mint,
+        ctx.accounts.xp_mint.key(),
+        AcademyError::MintMismatch
+    );
+
+    ctx.accounts.mentor_role.consume_review(now)?;
+    enrollment.set_lesson_complete(lesson_index)?;
+
+    let xp_earned = course.xp_per_lesson;
+    mint_xp(
+        &ctx.accounts.config,
+        &ctx.accounts.xp_mint,
+        &ctx.accounts.learner_token_account,
+        ctx.accounts
+            .season_mint
+            .as_ref()
+            .zip(ctx.accounts.learner_season_account.as_ref()),
+        &ctx.accounts.token_program,
+        xp_earned as u64,
+    )?;
+    record_leaderboards(
+        &ctx.accounts.config,
+        &mut ctx.accounts.leaderboard,
+        ctx.accounts.season_leaderboard.as_deref_mut(),
+        &ctx.accounts.learner_token_account,
+        ctx.accounts.learner_season_account.as_ref(),
+        xp_earned as u64,
+    )?;
+    record_guild_xp(
+        &ctx.accounts.guild_membership,
+        ctx.accounts.guild.as_deref_mut(),
+        xp_earned as u64,
+    )?;
+
+    let mentor_xp = ctx.accounts.mentor_role.review_xp;
+    if mentor_xp > 0 {
+        let mentor_token_account = ctx
+            .accounts
+            .mentor_token_account
+            .as_ref()
+            .ok_or_else(|| error!(AcademyError::MissingMentorRewardAccounts))?;
+        require_keys_eq!(
+            mentor_token_account.owner,
+            mentor,
+            AcademyError::InvalidTokenAccount
+        );
+        require_keys_eq!(
+            mentor_token_account.mint,
+            ctx.accounts.xp_mint.key(),
+            AcademyError::MintMismatch
+        );
+        mint_xp(
+            &ctx.accounts.config,
+            &ctx.accounts.xp_mint,
+            mentor_token_account,
+            ctx.accounts
+                .season_mint
+                .as_ref()
+                .zip(ctx.accounts.mentor_season_account.as_ref()),
+            &ctx.accounts.token_program,
+            mentor_xp as u64,
+        )?;
+        record_leaderboards(
+            &ctx.accounts.config,
+            &mut ctx.accounts.leaderboard,
+            ctx.accounts.season_leaderboard.as_deref_mut(),
+            mentor_token_account,
+            ctx.accounts.mentor_season_account.as_ref(),
+            mentor_xp as u64,
+        )?;
+        // A mentor in the learner's guild reuses `guild`; passing it twice
+        // would let the second write clobber the first.
+        if let (Some(guild), Some(mentor_guild)) = (
+            ctx.accounts.guild.as_ref(),
+            ctx.accounts.mentor_guild.as_ref(),
+        ) {
+            require_keys_neq!(
+                guild.key(),
+                mentor_guild.key(),
+                AcademyError::InvalidGuildMembership
+            );
+        }
+        let mentor_guild = match ctx.accounts.mentor_guild.as_deref_mut() {
+            Some(mentor_guild) => Some(mentor_guild),
+            None => ctx.accounts.guild.as_deref_mut(),
+        };
+        record_guild_xp(
+            &ctx.accounts.mentor_guild_membership,
+            mentor_guild,
+            mentor_xp as u64,
+        )?;
+    }
+
+    let mentor_review = &mut ctx.accounts.mentor_review;
+    mentor_review.course = ctx.accounts.course.key();
+    mentor_review.learner = ctx.accounts.learner.key();
+    mentor_review.enrolled_at = ctx.accounts.enrollment.enrolled_at;
+    mentor_review.lesson_index = lesson_index;
+    mentor_review.mentor = mentor;
+    mentor_review.xp_earned = xp_earned;
+    mentor_review.mentor_xp = mentor_xp;
+    mentor_review.reviewed_at = now;
+    mentor_review.bump = ctx.bumps.mentor_review;
+    mentor_review.version = CURRENT_ACCOUNT_VERSION;
+
+    emit!(LessonMentorReviewed {
+        learner: ctx.accounts.learner.key(),
+        course_id: ctx.accounts.course.course_id.clone(),
+        lesson_index,
+        mentor,
+        xp_earned,
+        mentor_xp,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(lesson_index: u8)]
+pub struct CompleteLessonMentor<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
+    pub course: Box<Account<'info, Course>>,
+    #[account(
+        mut,
+        seeds = [b"enrollment", &id_seed(&enrollment.course_id)[..], learner.key().as_ref()],
+        bump = enrollment.bump
+    )]
+    pub enrollment: Box<Account<'info, Enrollment>>,
+    /// CHECK: Learner pubkey used for ownership checks and enrollment seed validation.
+    pub learner: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub learner_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub xp_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub season_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub learner_season_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &LIFETIME_LEADERBOARD.to_le_bytes()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &config.current_season.to_le_bytes()],
+        bump = season_leaderboard.bump
+    )]
+    pub season_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    /// CHECK: The learner's `GuildMembership` PDA; may not exist.
+    #[account(seeds = [b"guild_membership", learner_token_account.owner.as_ref()], bump)]
+    pub guild_membership: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub guild: Option<Box<Account<'info, Guild>>>,
+    #[account(
+        mut,
+        seeds = [b"mentor", mentor.key().as_ref()],
+        bump = mentor_role.bump
+    )]
+    pub mentor_role: Box<Account<'info, MentorRole>>,
+    #[account(
+        init,
+        payer = mentor,
+        space = 8 + MentorReview::LEN,
+        seeds = [
+            b"mentor_review",
+            course.key().as_ref(),
+            learner.key().as_ref(),
+            &enrollment.enrolled_at.to_le_bytes(),
+            &[lesson_index],
+        ],
+        bump
+    )]
+    pub mentor_review: Box<Account<'info, MentorReview>>,
+    /// Only read when the mentor role pays `review_xp`.
+    #[account(mut)]
+    pub mentor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub mentor_season_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: The mentor's `GuildMembership` PDA; may not exist.
+    #[account(seeds = [b"guild_membership", mentor.key().as_ref()], bump)]
+    pub mentor_guild_membership: UncheckedAccount<'info>,
+    /// Omitted when the mentor shares the learner's guild.
+    #[account(mut)]
+    pub mentor_guild: Option<Box<Account<'info, Guild>>>,
+    #[account(mut)]
+    pub mentor: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct LessonMentorReviewed {
+    pub learner: Pubkey,
+    pub course_id: String,
+    pub lesson_index: u8,
+    pub mentor: Pubkey,
+    pub xp_earned: u32,
+    pub mentor_xp: u32,
+    pub timestamp: i64,
+}
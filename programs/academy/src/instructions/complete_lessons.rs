@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    instructions::complete_lesson::*,
+    state::Enrollment,
+    utils::{id_seed, mint_xp, record_guild_xp, record_leaderboards},
+};
+
+pub fn complete_lessons(ctx: Context<CompleteLessons>, lesson_mask: [u64; 4]) -> Result<()> {
+    process_complete_lessons(
+        &mut ctx.accounts.lessons,
+        &mut ctx.accounts.enrollment,
+        lesson_mask,
+    )
+}
+
+// Shared by `complete_lessons` and `complete_lessons_attested`; as with
+// `process_complete_lesson`, the caller has already tied the batch to
+// `backend_signer_role`.
+pub(crate) fn process_complete_lessons(
+    accounts: &mut LessonCompletion,
+    enrollment: &mut Enrollment,
+    lesson_mask: [u64; 4],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        accounts.backend_signer_role.is_valid_at(now),
+        AcademyError::BackendSignerNotValid
+    );
+    require_keys_eq!(
+        accounts.xp_mint.key(),
+        accounts.config.xp_mint,
+        AcademyError::MintMismatch
+    );
+
+    let course = &accounts.course;
+
+    require!(course.is_active, AcademyError::CourseNotActive);
+    require!(
+        enrollment.course_id == course.course_id,
+        AcademyError::InvalidCourseId
+    );
+    require_keys_eq!(
+        enrollment.learner,
+        accounts.learner.key(),
+        AcademyError::Unauthorized
+    );
+    require!(!enrollment.is_expired(now), AcademyError::EnrollmentExpired);
+
+    require_keys_eq!(
+        accounts.learner_token_account.owner,
+        accounts.learner.key(),
+        AcademyError::InvalidTokenAccount
+    );
+    require_keys_eq!(
+        accounts.learner_token_account.mint,
+        accounts.xp_mint.key(),
+        AcademyError::MintMismatch
+    );
+
+    require!(
+        !course.has_mentor_graded(&lesson_mask),
+        AcademyError::LessonRequiresMentor
+    );
+
+    let lesson_indices = enrollment.set_lessons_complete(lesson_mask, course.lesson_count)?;
+    let xp_earned = u64::from(course.xp_per_lesson)
+        .checked_mul(lesson_indices.len() as u64)
+        .ok_or_else(|| error!(AcademyError::Overflow))?;
+
+    mint_xp(
+        &accounts.config,
+        &accounts.xp_mint,
+        &accounts.learner_token_account,
+        accounts
+            .season_mint
+            .as_ref()
+            .zip(accounts.learner_season_account.as_ref()),
+        &accounts.token_program,
+        xp_earned,
+    )?;
+    record_leaderboards(
+        &accounts.config,
+        &mut accounts.leaderboard,
+        accounts.season_leaderboard.as_deref_mut(),
+        &accounts.learner_token_account,
+        accounts.learner_season_account.as_ref(),
+        xp_earned,
+    )?;
+    record_guild_xp(
+        &accounts.guild_membership,
+        accounts.guild.as_deref_mut(),
+        xp_earned,
+    )?;
+    accounts.backend_signer_role.record_mint(xp_earned)?;
+
+    emit!(LessonsCompleted {
+        learner: accounts.learner.key(),
+        course_id: course.course_id.clone(),
+        lesson_indices,
+        xp_earned,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CompleteLessons<'info> {
+    pub lessons: LessonCompletion<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"enrollment",
+            &id_seed(&enrollment.course_id)[..],
+            lessons.learner.key().as_ref()
+        ],
+        bump = enrollment.bump
+    )]
+    pub enrollment: Account<'info, Enrollment>,
+    #[account(address = lessons.backend_signer_role.signer @ AcademyError::Unauthorized)]
+    pub backend_signer: Signer<'info>,
+}
+
+#[event]
+pub struct LessonsCompleted {
+    pub learner: Pubkey,
+    pub course_id: String,
+    pub lesson_indices: Vec<u8>,
+    pub xp_earned: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::ID as INSTRUCTIONS_SYSVAR_ID;
+
+use crate::{
+    errors::AcademyError,
+    instructions::{complete_lesson::*, complete_lessons::*},
+    state::{AttestationNonces, Enrollment, CURRENT_ACCOUNT_VERSION},
+    utils::{id_seed, lessons_attestation_message, verify_backend_attestation},
+};
+
+pub fn complete_lessons_attested(
+    ctx: Context<CompleteLessonsAttested>,
+    lesson_mask: [u64; 4],
+    nonce: u64,
+    expiry: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(now <= expiry, AcademyError::AttestationExpired);
+
+    let learner = ctx.accounts.learner.key();
+    let message = lessons_attestation_message(
+        &ctx.accounts.lessons.config,
+        &learner,
+        &ctx.accounts.lessons.course.key(),
+        &lesson_mask,
+        nonce,
+        expiry,
+    );
+    verify_backend_attestation(
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.lessons.backend_signer_role.signer,
+        &message,
+    )?;
+
+    let nonces = &mut ctx.accounts.attestation_nonces;
+    if nonces.learner == Pubkey::default() {
+        nonces.learner = learner;
+        nonces.bump = ctx.bumps.attestation_nonces;
+        nonces.version = CURRENT_ACCOUNT_VERSION;
+    }
+    nonces.consume(nonce)?;
+
+    process_complete_lessons(
+        &mut ctx.accounts.lessons,
+        &mut ctx.accounts.enrollment,
+        lesson_mask,
+    )
+}
+
+#[derive(Accounts)]
+pub struct CompleteLessonsAttested<'info> {
+    pub lessons: LessonCompletion<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"enrollment",
+            &id_seed(&enrollment.course_id)[..],
+            lessons.learner.key().as_ref()
+        ],
+        bump = enrollment.bump
+    )]
+    pub enrollment: Account<'info, Enrollment>,
+    #[account(
+        init_if_needed,
+        payer = learner,
+        space = 8 + AttestationNonces::LEN,
+        seeds = [b"attestation_nonces", learner.key().as_ref()],
+        bump
+    )]
+    pub attestation_nonces: Account<'info, AttestationNonces>,
+    #[account(mut, address = lessons.learner.key() @ AcademyError::Unauthorized)]
+    pub learner: Signer<'info>,
+    /// CHECK: Instructions sysvar, address-checked; read to find the Ed25519 attestation.
+    #[account(address = INSTRUCTIONS_SYSVAR_ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -2,14 +2,18 @@ use anchor_lang::prelude::*;
 
 use crate::{
     errors::AcademyError,
-    state::{AchievementType, Config, CreateAchievementTypeParams, MAX_ACHIEVEMENT_ID_LEN, MAX_ACHIEVEMENT_NAME_LEN, MAX_METADATA_URI_LEN},
+    state::{
+        AchievementType, Config, CreateAchievementTypeParams, CURRENT_ACCOUNT_VERSION,
+        MAX_ACHIEVEMENT_ID_LEN, MAX_ACHIEVEMENT_NAME_LEN, MAX_METADATA_URI_LEN,
+    },
+    utils::{id_seed, is_valid_slug},
 };
 
 pub fn create_achievement_type(
     ctx: Context<CreateAchievementType>,
     params: CreateAchievementTypeParams,
 ) -> Result<()> {
-    require!(!params.achievement_id.is_empty(), AcademyError::InvalidAchievementId);
+    require!(is_valid_slug(&params.achievement_id), AcademyError::InvalidAchievementId);
     require!(
         params.achievement_id.len() <= MAX_ACHIEVEMENT_ID_LEN,
         AcademyError::InvalidAchievementId
@@ -41,6 +45,7 @@ pub fn create_achievement_type(
     achievement_type.is_active = true;
     achievement_type.created_at = Clock::get()?.unix_timestamp;
     achievement_type.bump = ctx.bumps.achievement_type;
+    achievement_type.version = CURRENT_ACCOUNT_VERSION;
 
     emit!(AchievementTypeCreated {
         achievement_id: achievement_type.achievement_id.clone(),
@@ -58,7 +63,7 @@ pub struct CreateAchievementType<'info> {
         init,
         payer = payer,
         space = 8 + AchievementType::LEN,
-        seeds = [b"achievement", params.achievement_id.as_bytes()],
+        seeds = [b"achievement", &id_seed(&params.achievement_id)[..]],
         bump
     )]
     pub achievement_type: Account<'info, AchievementType>,
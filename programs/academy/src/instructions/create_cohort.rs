@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{Cohort, Config, Course, CreateCohortParams, CURRENT_ACCOUNT_VERSION},
+    utils::id_seed,
+};
+
+pub fn create_cohort(ctx: Context<CreateCohort>, params: CreateCohortParams) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    require!(
+        authority == ctx.accounts.config.authority || authority == ctx.accounts.course.creator,
+        AcademyError::Unauthorized
+    );
+    require!(
+        params.starts_at < params.ends_at,
+        AcademyError::InvalidCohortSchedule
+    );
+    require!(params.capacity > 0, AcademyError::InvalidCohortSchedule);
+
+    let cohort = &mut ctx.accounts.cohort;
+    cohort.course = ctx.accounts.course.key();
+    cohort.cohort_id = params.cohort_id;
+    cohort.starts_at = params.starts_at;
+    cohort.ends_at = params.ends_at;
+    cohort.capacity = params.capacity;
+    cohort.enrolled_count = 0;
+    cohort.completed_count = 0;
+    cohort.waitlist_enabled = params.waitlist_enabled;
+    cohort.waitlist_count = 0;
+    cohort.created_at = Clock::get()?.unix_timestamp;
+    cohort.bump = ctx.bumps.cohort;
+    cohort.version = CURRENT_ACCOUNT_VERSION;
+    cohort.occupied_seats = 0;
+
+    emit!(CohortCreated {
+        course_id: ctx.accounts.course.course_id.clone(),
+        cohort_id: params.cohort_id,
+        starts_at: params.starts_at,
+        ends_at: params.ends_at,
+        capacity: params.capacity,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateCohortParams)]
+pub struct CreateCohort<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
+    pub course: Account<'info, Course>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Cohort::LEN,
+        seeds = [b"cohort", course.key().as_ref(), &params.cohort_id.to_le_bytes()],
+        bump
+    )]
+    pub cohort: Account<'info, Cohort>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct CohortCreated {
+    pub course_id: String,
+    pub cohort_id: u32,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub capacity: u32,
+}
@@ -2,11 +2,43 @@ use anchor_lang::prelude::*;
 
 use crate::{
     errors::AcademyError,
-    state::{Config, Course, CreateCourseParams, MAX_COURSE_ID_LEN},
+    state::{
+        Config, Course, CourseStatus, CreateCourseParams, CreatorVault, BPS_DENOMINATOR,
+        CURRENT_ACCOUNT_VERSION, MAX_COURSE_ID_LEN,
+    },
+    utils::{id_seed, is_valid_slug},
 };
 
 pub fn create_course(ctx: Context<CreateCourse>, params: CreateCourseParams) -> Result<()> {
-    require!(!params.course_id.is_empty(), AcademyError::InvalidCourseId);
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        ctx.accounts.config.authority,
+        AcademyError::Unauthorized
+    );
+
+    let course = &mut ctx.accounts.course;
+    init_course(course, params, CourseStatus::Approved, ctx.bumps.course)?;
+    ctx.accounts.creator_vault.set_inner(CreatorVault::new(
+        course.key(),
+        course.creator,
+        ctx.bumps.creator_vault,
+    ));
+
+    emit!(CourseCreated {
+        course_id: course.course_id.clone(),
+        creator: course.creator,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn init_course(
+    course: &mut Course,
+    params: CreateCourseParams,
+    status: CourseStatus,
+    bump: u8,
+) -> Result<()> {
+    require!(is_valid_slug(&params.course_id), AcademyError::InvalidCourseId);
     require!(
         params.course_id.len() <= MAX_COURSE_ID_LEN,
         AcademyError::InvalidCourseId
@@ -15,15 +47,22 @@ pub fn create_course(ctx: Context<CreateCourse>, params: CreateCourseParams) ->
         params.lesson_count > 0,
         AcademyError::InvalidLessonCount
     );
+    if let Some(pricing) = params.pricing {
+        require!(pricing.amount > 0, AcademyError::InvalidAmount);
+        require!(
+            pricing.creator_share_bps <= BPS_DENOMINATOR,
+            AcademyError::InvalidBasisPoints
+        );
+    }
+    if let Some(max_duration_secs) = params.max_duration_secs {
+        require!(max_duration_secs > 0, AcademyError::InvalidAmount);
+    }
+    if let Some(commitment) = params.commitment {
+        require!(commitment.amount > 0, AcademyError::InvalidAmount);
+        require!(commitment.deadline_secs > 0, AcademyError::InvalidAmount);
+    }
 
-    require_keys_eq!(
-        ctx.accounts.authority.key(),
-        ctx.accounts.config.authority,
-        AcademyError::Unauthorized
-    );
-
-    let course = &mut ctx.accounts.course;
-    course.course_id = params.course_id.clone();
+    course.course_id = params.course_id;
     course.creator = params.creator;
     course.content_tx_id = params.content_tx_id;
     course.lesson_count = params.lesson_count;
@@ -35,14 +74,17 @@ pub fn create_course(ctx: Context<CreateCourse>, params: CreateCourseParams) ->
     course.creator_reward_xp = params.creator_reward_xp;
     course.min_completions_for_reward = params.min_completions_for_reward;
     course.completion_count = 0;
-    course.is_active = true;
+    course.is_active = status == CourseStatus::Approved;
+    course.status = status;
+    course.pricing = params.pricing;
+    course.commitment = params.commitment;
+    course.max_duration_secs = params.max_duration_secs;
+    course.allow_progress_restore = params.allow_progress_restore;
+    course.pending_creator_revenue = 0;
+    course.pending_treasury_revenue = 0;
     course.created_at = Clock::get()?.unix_timestamp;
-    course.bump = ctx.bumps.course;
-
-    emit!(CourseCreated {
-        course_id: course.course_id.clone(),
-        creator: course.creator,
-    });
+    course.bump = bump;
+    course.version = CURRENT_ACCOUNT_VERSION;
 
     Ok(())
 }
@@ -54,10 +96,18 @@ pub struct CreateCourse<'info> {
         init,
         payer = authority,
         space = 8 + Course::LEN,
-        seeds = [b"course", params.course_id.as_bytes()],
+        seeds = [b"course", &id_seed(&params.course_id)[..]],
         bump
     )]
     pub course: Account<'info, Course>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CreatorVault::LEN,
+        seeds = [b"creator_vault", course.key().as_ref()],
+        bump
+    )]
+    pub creator_vault: Account<'info, CreatorVault>,
     #[account(seeds = [b"config"], bump = config.bump)]
     pub config: Account<'info, Config>,
     #[account(mut)]
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::{
+    errors::AcademyError,
+    state::{
+        Config, CreateDistributionParams, RewardDistribution, CURRENT_ACCOUNT_VERSION,
+        MAX_DISTRIBUTION_RECIPIENTS,
+    },
+};
+
+pub fn create_distribution(
+    ctx: Context<CreateDistribution>,
+    params: CreateDistributionParams,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        config.authority,
+        AcademyError::Unauthorized
+    );
+    // Prizes are paid once a season's standings are final.
+    require!(
+        params.season <= config.current_season
+            && !(config.season_active && params.season == config.current_season),
+        AcademyError::InvalidSeason
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        params.total_amount > 0
+            && params.num_recipients > 0
+            && params.num_recipients <= MAX_DISTRIBUTION_RECIPIENTS
+            && params.expires_at > now,
+        AcademyError::InvalidDistribution
+    );
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.funding_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.distribution_vault.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        params.total_amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    let distribution = &mut ctx.accounts.distribution;
+    distribution.season = params.season;
+    distribution.mint = ctx.accounts.mint.key();
+    distribution.merkle_root = params.merkle_root;
+    distribution.total_amount = params.total_amount;
+    distribution.claimed_amount = 0;
+    distribution.num_recipients = params.num_recipients;
+    distribution.claimed_count = 0;
+    distribution.expires_at = params.expires_at;
+    distribution.created_at = now;
+    distribution.bump = ctx.bumps.distribution;
+    distribution.version = CURRENT_ACCOUNT_VERSION;
+
+    emit!(DistributionCreated {
+        season: params.season,
+        mint: distribution.mint,
+        merkle_root: params.merkle_root,
+        total_amount: params.total_amount,
+        num_recipients: params.num_recipients,
+        expires_at: params.expires_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateDistributionParams)]
+pub struct CreateDistribution<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RewardDistribution::LEN,
+        seeds = [b"distribution".as_ref(), &params.season.to_le_bytes()],
+        bump
+    )]
+    pub distribution: Box<Account<'info, RewardDistribution>>,
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"distribution_vault", distribution.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = distribution_vault,
+        token::token_program = token_program,
+    )]
+    pub distribution_vault: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, token::mint = mint, token::authority = authority)]
+    pub funding_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct DistributionCreated {
+    pub season: u32,
+    pub mint: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub total_amount: u64,
+    pub num_recipients: u32,
+    pub expires_at: i64,
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{
+        CreateGuildParams, Guild, GuildMembership, CURRENT_ACCOUNT_VERSION, MAX_GUILD_ID_LEN,
+        MAX_GUILD_NAME_LEN,
+    },
+    utils::{id_seed, is_valid_slug},
+};
+
+// The leader founds the guild and joins it in the same step, so the usual
+// switch cooldown applies to them too.
+pub fn create_guild(ctx: Context<CreateGuild>, params: CreateGuildParams) -> Result<()> {
+    require!(
+        is_valid_slug(&params.guild_id),
+        AcademyError::InvalidGuildId
+    );
+    require!(
+        params.guild_id.len() <= MAX_GUILD_ID_LEN,
+        AcademyError::InvalidGuildId
+    );
+    require!(
+        !params.name.is_empty() && params.name.len() <= MAX_GUILD_NAME_LEN,
+        AcademyError::InvalidMetadata
+    );
+    require!(params.member_cap > 0, AcademyError::InvalidAmount);
+
+    let now = Clock::get()?.unix_timestamp;
+    let leader = ctx.accounts.leader.key();
+
+    let guild = &mut ctx.accounts.guild;
+    guild.guild_id = params.guild_id.clone();
+    guild.name = params.name;
+    guild.leader = leader;
+    guild.member_cap = params.member_cap;
+    guild.member_count = 0;
+    guild.total_xp = 0;
+    guild.created_at = now;
+    guild.bump = ctx.bumps.guild;
+    guild.version = CURRENT_ACCOUNT_VERSION;
+
+    let membership = &mut ctx.accounts.guild_membership;
+    if membership.version == 0 {
+        membership.learner = leader;
+        membership.bump = ctx.bumps.guild_membership;
+        membership.version = CURRENT_ACCOUNT_VERSION;
+    }
+    membership.join(guild, now)?;
+
+    emit!(GuildCreated {
+        guild_id: params.guild_id,
+        guild: guild.key(),
+        leader,
+        member_cap: params.member_cap,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateGuildParams)]
+pub struct CreateGuild<'info> {
+    #[account(
+        init,
+        payer = leader,
+        space = 8 + Guild::LEN,
+        seeds = [b"guild", &id_seed(&params.guild_id)[..]],
+        bump
+    )]
+    pub guild: Account<'info, Guild>,
+    #[account(
+        init_if_needed,
+        payer = leader,
+        space = 8 + GuildMembership::LEN,
+        seeds = [b"guild_membership", leader.key().as_ref()],
+        bump
+    )]
+    pub guild_membership: Account<'info, GuildMembership>,
+    #[account(mut)]
+    pub leader: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct GuildCreated {
+    pub guild_id: String,
+    pub guild: Pubkey,
+    pub leader: Pubkey,
+    pub member_cap: u32,
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{
+        Config, CreateRewardItemParams, RewardItem, CURRENT_ACCOUNT_VERSION, MAX_METADATA_URI_LEN,
+        MAX_REWARD_ID_LEN,
+    },
+    utils::{id_seed, is_valid_slug},
+};
+
+pub fn create_reward_item(
+    ctx: Context<CreateRewardItem>,
+    params: CreateRewardItemParams,
+) -> Result<()> {
+    require!(
+        is_valid_slug(&params.reward_id),
+        AcademyError::InvalidRewardId
+    );
+    require!(
+        params.reward_id.len() <= MAX_REWARD_ID_LEN,
+        AcademyError::InvalidRewardId
+    );
+    require!(
+        params.metadata_uri.len() <= MAX_METADATA_URI_LEN,
+        AcademyError::InvalidMetadata
+    );
+    require!(params.price_xp > 0, AcademyError::InvalidAmount);
+    require!(params.max_supply > 0, AcademyError::InvalidAmount);
+    require!(params.per_learner_limit > 0, AcademyError::InvalidAmount);
+    require!(
+        params.starts_at < params.ends_at,
+        AcademyError::InvalidRewardWindow
+    );
+    if let Some(payout) = params.payout {
+        require!(payout.amount > 0, AcademyError::InvalidAmount);
+    }
+
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        ctx.accounts.config.authority,
+        AcademyError::Unauthorized
+    );
+
+    let reward_item = &mut ctx.accounts.reward_item;
+    reward_item.reward_id = params.reward_id.clone();
+    reward_item.metadata_uri = params.metadata_uri;
+    reward_item.price_xp = params.price_xp;
+    reward_item.max_supply = params.max_supply;
+    reward_item.redeemed_count = 0;
+    reward_item.per_learner_limit = params.per_learner_limit;
+    reward_item.starts_at = params.starts_at;
+    reward_item.ends_at = params.ends_at;
+    reward_item.is_active = true;
+    reward_item.payout = params.payout;
+    reward_item.created_at = Clock::get()?.unix_timestamp;
+    reward_item.bump = ctx.bumps.reward_item;
+    reward_item.version = CURRENT_ACCOUNT_VERSION;
+
+    emit!(RewardItemCreated {
+        reward_id: params.reward_id,
+        price_xp: params.price_xp,
+        max_supply: params.max_supply,
+        payout_mint: params.payout.map(|payout| payout.mint),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateRewardItemParams)]
+pub struct CreateRewardItem<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + RewardItem::LEN,
+        seeds = [b"reward_item", &id_seed(&params.reward_id)[..]],
+        bump
+    )]
+    pub reward_item: Account<'info, RewardItem>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct RewardItemCreated {
+    pub reward_id: String,
+    pub price_xp: u64,
+    pub max_supply: u32,
+    pub payout_mint: Option<Pubkey>,
+}
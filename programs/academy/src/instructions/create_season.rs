@@ -1,34 +1,119 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token};
-use crate::state::Config;
-
-pub fn create_season(
-    ctx: Context<CreateSeasonAccounts>,
-    season: u32,
-    _metadata_uri: String,
-) -> Result<()> {
+use anchor_spl::token_2022::Token2022;
+
+use crate::{
+    errors::AcademyError,
+    state::{
+        Config, CreateSeasonParams, Leaderboard, Season, CURRENT_ACCOUNT_VERSION,
+        MAX_METADATA_URI_LEN, MAX_TOKEN_NAME_LEN, MAX_TOKEN_SYMBOL_LEN,
+    },
+    utils::{create_xp_mint, XpMintMetadata},
+};
+
+pub fn create_season(ctx: Context<CreateSeason>, params: CreateSeasonParams) -> Result<()> {
+    require!(
+        !params.name.is_empty() && params.name.len() <= MAX_TOKEN_NAME_LEN,
+        AcademyError::InvalidMetadata
+    );
+    require!(
+        !params.symbol.is_empty() && params.symbol.len() <= MAX_TOKEN_SYMBOL_LEN,
+        AcademyError::InvalidMetadata
+    );
+    require!(
+        params.uri.len() <= MAX_METADATA_URI_LEN,
+        AcademyError::InvalidMetadata
+    );
+
+    let config = &ctx.accounts.config;
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        config.authority,
+        AcademyError::Unauthorized
+    );
+    require!(!config.season_active, AcademyError::SeasonAlreadyActive);
+    require!(
+        params.season > config.current_season,
+        AcademyError::InvalidSeason
+    );
+
+    create_xp_mint(
+        &ctx.accounts.season_mint,
+        &ctx.accounts.config.to_account_info(),
+        ctx.accounts.config.bump,
+        &ctx.accounts.authority,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        XpMintMetadata {
+            name: &params.name,
+            symbol: &params.symbol,
+            uri: &params.uri,
+        },
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let season_mint = ctx.accounts.season_mint.key();
+
+    let season = &mut ctx.accounts.season;
+    season.season = params.season;
+    season.mint = season_mint;
+    season.started_at = now;
+    season.closed_at = 0;
+    season.bump = ctx.bumps.season;
+    season.version = CURRENT_ACCOUNT_VERSION;
+
+    let season_leaderboard = &mut ctx.accounts.season_leaderboard;
+    season_leaderboard.season = params.season;
+    season_leaderboard.count = 0;
+    season_leaderboard.updated_at = now;
+    season_leaderboard.bump = ctx.bumps.season_leaderboard;
+    season_leaderboard.version = CURRENT_ACCOUNT_VERSION;
+
     let config = &mut ctx.accounts.config;
-    
-    require!(!config.season_closed, crate::errors::AcademyError::SeasonNotInitialized);
-    
-    config.current_season = season;
-    config.current_mint = ctx.accounts.xp_mint.key();
-    config.season_closed = false;
-
-    msg!("✅ Season {} created with mint: {}", season, ctx.accounts.xp_mint.key());
+    config.current_season = params.season;
+    config.season_mint = season_mint;
+    config.season_active = true;
+
+    emit!(SeasonStarted {
+        season: params.season,
+        mint: season_mint,
+        started_at: now,
+    });
+
     Ok(())
 }
 
-pub struct CreateSeason;
-
 #[derive(Accounts)]
-pub struct CreateSeasonAccounts<'info> {
-    #[account(mut)]
+#[instruction(params: CreateSeasonParams)]
+pub struct CreateSeason<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
     pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Season::LEN,
+        seeds = [b"season".as_ref(), &params.season.to_le_bytes()],
+        bump
+    )]
+    pub season: Account<'info, Season>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Leaderboard::LEN,
+        seeds = [b"leaderboard".as_ref(), &params.season.to_le_bytes()],
+        bump
+    )]
+    pub season_leaderboard: Box<Account<'info, Leaderboard>>,
     #[account(mut)]
-    pub xp_mint: Account<'info, Mint>,
+    pub season_mint: Signer<'info>,
     #[account(mut)]
     pub authority: Signer<'info>,
-    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[event]
+pub struct SeasonStarted {
+    pub season: u32,
+    pub mint: Pubkey,
+    pub started_at: i64,
 }
@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::AcademyError, state::{AchievementType, Config}};
+use crate::{
+    errors::AcademyError,
+    state::{AchievementType, Config},
+    utils::id_seed,
+};
 
 pub fn deactivate_achievement_type(ctx: Context<DeactivateAchievementType>) -> Result<()> {
     require_keys_eq!(
@@ -25,7 +29,7 @@ pub struct DeactivateAchievementType<'info> {
     pub config: Account<'info, Config>,
     #[account(
         mut,
-        seeds = [b"achievement", achievement_type.achievement_id.as_bytes()],
+        seeds = [b"achievement", &id_seed(&achievement_type.achievement_id)[..]],
         bump = achievement_type.bump
     )]
     pub achievement_type: Account<'info, AchievementType>,
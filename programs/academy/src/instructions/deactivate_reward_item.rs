@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{Config, RewardItem},
+    utils::id_seed,
+};
+
+pub fn deactivate_reward_item(ctx: Context<DeactivateRewardItem>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        ctx.accounts.config.authority,
+        AcademyError::Unauthorized
+    );
+
+    let reward_item = &mut ctx.accounts.reward_item;
+    reward_item.is_active = false;
+
+    emit!(RewardItemDeactivated {
+        reward_id: reward_item.reward_id.clone(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DeactivateRewardItem<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"reward_item", &id_seed(&reward_item.reward_id)[..]],
+        bump = reward_item.bump
+    )]
+    pub reward_item: Account<'info, RewardItem>,
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct RewardItemDeactivated {
+    pub reward_id: String,
+}
@@ -1,86 +1,343 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 
-use crate::{errors::AcademyError, state::{Course, Enrollment}};
+use crate::{
+    errors::AcademyError,
+    state::{
+        CompletionRecord, Course, CourseStatus, Enrollment, EnrollmentArchive,
+        ACCOUNT_RESERVED_LEN, CURRENT_ACCOUNT_VERSION,
+    },
+    utils::id_seed,
+};
 
 pub fn enroll(ctx: Context<Enroll>, course_id: String) -> Result<()> {
-    let course = &ctx.accounts.course;
+    process_enroll(
+        ctx.accounts,
+        ctx.bumps.enrollment,
+        ctx.remaining_accounts,
+        course_id,
+    )
+}
+
+pub(crate) fn process_enroll(
+    accounts: &mut Enroll,
+    enrollment_bump: u8,
+    remaining_accounts: &[AccountInfo],
+    course_id: String,
+) -> Result<()> {
+    let entry = &accounts.entry;
+    check_course_open(entry, &course_id)?;
+    check_prerequisite(&entry.course, &entry.learner.key(), remaining_accounts)?;
+
+    let lesson_flags = EnrollmentArchive::restored_lesson_flags(
+        &accounts.enrollment_archive.try_borrow_data()?,
+        entry.course.allow_progress_restore,
+    )?;
+    let stake_holder = accounts.enrollment.to_account_info();
+    let enrollment = admit_learner(
+        entry,
+        course_id,
+        lesson_flags,
+        Some((&stake_holder, &accounts.system_program)),
+    )?;
+    accounts.enrollment.set_inner(Enrollment {
+        bump: enrollment_bump,
+        ..enrollment
+    });
 
+    Ok(())
+}
+
+pub(crate) fn check_course_open(entry: &CourseEntry, course_id: &str) -> Result<()> {
+    let course = &entry.course;
+
+    require!(
+        course.status == CourseStatus::Approved,
+        AcademyError::CourseNotApproved
+    );
     require!(course.is_active, AcademyError::CourseNotActive);
     require!(course.course_id == course_id, AcademyError::InvalidCourseId);
+    // A compacted completion leaves no enrollment behind, so the record itself
+    // is what stops a finished course from being taken (and rewarded) again.
+    require!(
+        entry.completion_record.data_is_empty(),
+        AcademyError::CourseAlreadyCompleted
+    );
 
-    if let Some(prereq_course_key) = course.prerequisite {
-        require!(
-            ctx.remaining_accounts.len() >= 2,
-            AcademyError::MissingPrerequisiteEnrollment
-        );
+    Ok(())
+}
+
+// `remaining_accounts` carries the prerequisite course and either the
+// learner's finalized enrollment in it or the completion record it was
+// compacted into.
+fn check_prerequisite(
+    course: &Course,
+    learner: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    let Some(prereq_course_key) = course.prerequisite else {
+        return Ok(());
+    };
+    require!(
+        remaining_accounts.len() >= 2,
+        AcademyError::MissingPrerequisiteEnrollment
+    );
+
+    // Deserialize remaining accounts without Account wrapper to avoid lifetime issues
+    let prereq_course_info = &remaining_accounts[0];
+    require_keys_eq!(
+        prereq_course_info.key(),
+        prereq_course_key,
+        AcademyError::PrerequisiteNotMet
+    );
+    let prereq_course_data = Course::try_deserialize(
+        &mut &prereq_course_info.try_borrow_data()?[..],
+    )?;
 
-        // Deserialize remaining accounts without Account wrapper to avoid lifetime issues
-        let prereq_course_info = &ctx.remaining_accounts[0];
+    let prereq_proof_info = &remaining_accounts[1];
+    require_keys_eq!(
+        *prereq_proof_info.owner,
+        crate::ID,
+        AcademyError::PrerequisiteNotMet
+    );
+    let prereq_proof_data = prereq_proof_info.try_borrow_data()?;
+    if prereq_proof_data.starts_with(&CompletionRecord::DISCRIMINATOR) {
+        let completion_record =
+            CompletionRecord::try_deserialize(&mut &prereq_proof_data[..])?;
         require_keys_eq!(
-            prereq_course_info.key(),
+            completion_record.course,
             prereq_course_key,
             AcademyError::PrerequisiteNotMet
         );
-        let prereq_course_data = Course::try_deserialize(
-            &mut &prereq_course_info.try_borrow_data()?[..],
-        )?;
-
-        let prereq_enrollment_info = &ctx.remaining_accounts[1];
-        let prereq_enrollment_data = Enrollment::try_deserialize(
-            &mut &prereq_enrollment_info.try_borrow_data()?[..],
-        )?;
-        require!(
-            prereq_enrollment_data.course_id == prereq_course_data.course_id,
-            AcademyError::PrerequisiteNotMet
-        );
         require_keys_eq!(
-            prereq_enrollment_data.learner,
-            ctx.accounts.learner.key(),
-            AcademyError::PrerequisiteNotMet
-        );
-        require!(
-            prereq_enrollment_data.completed_at.is_some(),
+            completion_record.learner,
+            *learner,
             AcademyError::PrerequisiteNotMet
         );
+    } else {
+        let prereq_enrollment_data =
+            Enrollment::try_deserialize(&mut &prereq_proof_data[..])?;
+        check_prerequisite_enrollment(&prereq_enrollment_data, &prereq_course_data, learner)?;
     }
 
-    let enrollment = &mut ctx.accounts.enrollment;
-    enrollment.course_id = course_id.clone();
-    enrollment.learner = ctx.accounts.learner.key();
-    enrollment.lesson_flags = [0u64; 4];
-    enrollment.enrolled_at = Clock::get()?.unix_timestamp;
-    enrollment.completed_at = None;
-    enrollment.credential_asset = None;
-    enrollment.bump = ctx.bumps.enrollment;
+    Ok(())
+}
+
+pub(crate) fn check_prerequisite_enrollment(
+    prereq_enrollment: &Enrollment,
+    prereq_course: &Course,
+    learner: &Pubkey,
+) -> Result<()> {
+    require!(
+        prereq_enrollment.course_id == prereq_course.course_id,
+        AcademyError::PrerequisiteNotMet
+    );
+    require_keys_eq!(
+        prereq_enrollment.learner,
+        *learner,
+        AcademyError::PrerequisiteNotMet
+    );
+    require!(
+        prereq_enrollment.completed_at.is_some(),
+        AcademyError::PrerequisiteNotMet
+    );
+
+    Ok(())
+}
+
+// Takes the course fee and commitment stake and returns the new enrollment,
+// leaving `bump` for the caller. SOL stakes are moved into the stake holder.
+pub(crate) fn admit_learner<'info>(
+    entry: &CourseEntry<'info>,
+    course_id: String,
+    lesson_flags: [u64; 4],
+    native_stake: Option<(&AccountInfo<'info>, &Program<'info, System>)>,
+) -> Result<Enrollment> {
+    let course = &entry.course;
+
+    let escrowed_amount = match course.pricing {
+        Some(pricing) => {
+            let (
+                Some(payment_mint),
+                Some(learner_payment_account),
+                Some(course_escrow),
+                Some(token_program),
+            ) = (
+                entry.payment_mint.as_ref(),
+                entry.learner_payment_account.as_ref(),
+                entry.course_escrow.as_ref(),
+                entry.token_program.as_ref(),
+            )
+            else {
+                return err!(AcademyError::MissingPaymentAccounts);
+            };
+            require_keys_eq!(payment_mint.key(), pricing.mint, AcademyError::MintMismatch);
+            require_keys_eq!(course_escrow.mint, pricing.mint, AcademyError::MintMismatch);
+
+            token_interface::transfer_checked(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        from: learner_payment_account.to_account_info(),
+                        mint: payment_mint.to_account_info(),
+                        to: course_escrow.to_account_info(),
+                        authority: entry.learner.to_account_info(),
+                    },
+                ),
+                pricing.amount,
+                payment_mint.decimals,
+            )?;
+            pricing.amount
+        }
+        None => 0,
+    };
+
+    let now = Clock::get()?.unix_timestamp;
+    let (commitment_amount, commitment_deadline) = match course.commitment {
+        Some(commitment) => {
+            match commitment.mint {
+                None => {
+                    let (stake_holder, system_program) = native_stake
+                        .ok_or_else(|| error!(AcademyError::CompressedNativeCommitment))?;
+                    system_program::transfer(
+                        CpiContext::new(
+                            system_program.to_account_info(),
+                            Transfer {
+                                from: entry.learner.to_account_info(),
+                                to: stake_holder.clone(),
+                            },
+                        ),
+                        commitment.amount,
+                    )?
+                }
+                Some(mint) => {
+                    let (
+                        Some(commitment_mint),
+                        Some(learner_commitment_account),
+                        Some(commitment_vault),
+                        Some(token_program),
+                    ) = (
+                        entry.commitment_mint.as_ref(),
+                        entry.learner_commitment_account.as_ref(),
+                        entry.commitment_vault.as_ref(),
+                        entry.token_program.as_ref(),
+                    )
+                    else {
+                        return err!(AcademyError::MissingCommitmentAccounts);
+                    };
+                    require_keys_eq!(commitment_mint.key(), mint, AcademyError::MintMismatch);
+                    require_keys_eq!(commitment_vault.mint, mint, AcademyError::MintMismatch);
+
+                    token_interface::transfer_checked(
+                        CpiContext::new(
+                            token_program.to_account_info(),
+                            TransferChecked {
+                                from: learner_commitment_account.to_account_info(),
+                                mint: commitment_mint.to_account_info(),
+                                to: commitment_vault.to_account_info(),
+                                authority: entry.learner.to_account_info(),
+                            },
+                        ),
+                        commitment.amount,
+                        commitment_mint.decimals,
+                    )?;
+                }
+            }
+            let deadline = now
+                .checked_add(commitment.deadline_secs)
+                .ok_or_else(|| error!(AcademyError::Overflow))?;
+            (commitment.amount, deadline)
+        }
+        None => (0, 0),
+    };
+
+    let expires_at = course
+        .max_duration_secs
+        .map(|duration| {
+            now.checked_add(duration)
+                .ok_or_else(|| error!(AcademyError::Overflow))
+        })
+        .transpose()?;
+
+    let enrollment = Enrollment {
+        course_id: course_id.clone(),
+        learner: entry.learner.key(),
+        lesson_flags,
+        enrolled_at: now,
+        completed_at: None,
+        credential_asset: None,
+        escrowed_amount,
+        commitment_amount,
+        commitment_deadline,
+        expires_at,
+        cohort: None,
+        bump: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+        reserved: [0; ACCOUNT_RESERVED_LEN],
+    };
 
     emit!(Enrolled {
-        learner: ctx.accounts.learner.key(),
+        learner: entry.learner.key(),
         course_id,
+        restored_lessons: enrollment.completed_lesson_count(),
     });
 
-    Ok(())
+    Ok(enrollment)
 }
 
 #[derive(Accounts)]
 #[instruction(course_id: String)]
 pub struct Enroll<'info> {
-    #[account(seeds = [b"course", course_id.as_bytes()], bump = course.bump)]
-    pub course: Account<'info, Course>,
+    pub entry: CourseEntry<'info>,
     #[account(
         init,
-        payer = learner,
+        payer = entry.learner,
         space = 8 + Enrollment::LEN,
-        seeds = [b"enrollment", course_id.as_bytes(), learner.key().as_ref()],
+        seeds = [b"enrollment", &id_seed(&course_id)[..], entry.learner.key().as_ref()],
         bump
     )]
     pub enrollment: Account<'info, Enrollment>,
+    /// CHECK: The learner's `EnrollmentArchive` PDA for this course; may not exist.
+    #[account(
+        seeds = [
+            b"enrollment_archive",
+            &id_seed(&course_id)[..],
+            entry.learner.key().as_ref()
+        ],
+        bump
+    )]
+    pub enrollment_archive: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Everything enrolling touches apart from where the enrollment is stored.
+#[derive(Accounts)]
+pub struct CourseEntry<'info> {
+    #[account(seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
+    pub course: Account<'info, Course>,
     #[account(mut)]
     pub learner: Signer<'info>,
-    pub system_program: Program<'info, System>,
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub learner_payment_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, seeds = [b"course_escrow", course.key().as_ref()], bump)]
+    pub course_escrow: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub commitment_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub learner_commitment_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, seeds = [b"commitment_vault", course.key().as_ref()], bump)]
+    pub commitment_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    /// CHECK: The learner's `CompletionRecord` PDA for this course; must not exist.
+    #[account(seeds = [b"completion", course.key().as_ref(), learner.key().as_ref()], bump)]
+    pub completion_record: UncheckedAccount<'info>,
 }
 
 #[event]
 pub struct Enrolled {
     pub learner: Pubkey,
     pub course_id: String,
+    pub restored_lessons: u32,
 }
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use light_sdk::{
+    account::sha::LightAccount,
+    address::v1::derive_address,
+    cpi::{CpiAccounts, CpiInputs},
+    instruction::{PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::{
+    errors::AcademyError,
+    instructions::enroll::{
+        admit_learner, check_course_open, check_prerequisite_enrollment, CourseEntry,
+    },
+    state::{CompressedEnrollment, Course, Enrollment},
+    utils::id_seed,
+    LIGHT_CPI_SIGNER,
+};
+
+// The enrollment address is derived from the same seeds as the PDA, so the
+// address tree rejects a second enrollment in the same course outright. That
+// also rules out re-enrolling after a finalize, so there is no archive to
+// consult and every attempt starts with no lessons completed.
+pub fn enroll_compressed<'info>(
+    ctx: Context<'_, '_, '_, 'info, EnrollCompressed<'info>>,
+    course_id: String,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    prerequisite: Option<CompressedEnrollment>,
+) -> Result<()> {
+    let entry = &ctx.accounts.entry;
+    check_course_open(entry, &course_id)?;
+
+    let cpi_accounts = CpiAccounts::new(
+        entry.learner.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+    let mut compressed_accounts = Vec::with_capacity(2);
+
+    if let Some(prereq_course_key) = entry.course.prerequisite {
+        let (Some(prereq_course), Some(prereq)) =
+            (ctx.accounts.prerequisite_course.as_ref(), prerequisite)
+        else {
+            return err!(AcademyError::MissingPrerequisiteEnrollment);
+        };
+        require_keys_eq!(
+            prereq_course.key(),
+            prereq_course_key,
+            AcademyError::PrerequisiteNotMet
+        );
+        check_prerequisite_enrollment(&prereq.enrollment, prereq_course, &entry.learner.key())?;
+
+        // Writing the prerequisite back unchanged makes the validity proof
+        // cover it, which is what shows it exists in the state tree.
+        let prereq_enrollment =
+            LightAccount::<'_, Enrollment>::new_mut(&crate::ID, &prereq.meta, prereq.enrollment)
+                .map_err(ProgramError::from)?;
+        compressed_accounts.push(
+            prereq_enrollment
+                .to_account_info()
+                .map_err(ProgramError::from)?,
+        );
+    }
+
+    let address_tree = address_tree_info
+        .get_tree_pubkey(&cpi_accounts)
+        .map_err(ProgramError::from)?;
+    let (address, address_seed) = derive_address(
+        &[
+            b"enrollment",
+            &id_seed(&course_id)[..],
+            entry.learner.key().as_ref(),
+        ],
+        &address_tree,
+        &crate::ID,
+    );
+
+    let mut enrollment = LightAccount::<'_, Enrollment>::new_init(
+        &crate::ID,
+        Some(address),
+        output_state_tree_index,
+    );
+    *enrollment = admit_learner(entry, course_id, [0; 4], None)?;
+    compressed_accounts.push(enrollment.to_account_info().map_err(ProgramError::from)?);
+
+    CpiInputs::new_with_address(
+        proof,
+        compressed_accounts,
+        vec![address_tree_info.into_new_address_params_packed(address_seed)],
+    )
+    .invoke_light_system_program(cpi_accounts)
+    .map_err(ProgramError::from)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct EnrollCompressed<'info> {
+    pub entry: CourseEntry<'info>,
+    pub prerequisite_course: Option<Account<'info, Course>>,
+}
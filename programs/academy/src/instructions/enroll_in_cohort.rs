@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    instructions::enroll::*,
+    state::{Cohort, CohortWaitlistEntry},
+};
+
+pub fn enroll_in_cohort(
+    ctx: Context<EnrollInCohort>,
+    course_id: String,
+    cohort_id: u32,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    {
+        let cohort = &ctx.accounts.cohort;
+        require!(cohort.cohort_id == cohort_id, AcademyError::CohortMismatch);
+        require!(now < cohort.ends_at, AcademyError::CohortEnded);
+        require!(!cohort.is_full(), AcademyError::CohortFull);
+    }
+
+    process_enroll(
+        &mut ctx.accounts.enroll,
+        ctx.bumps.enroll.enrollment,
+        ctx.remaining_accounts,
+        course_id,
+    )?;
+
+    let cohort = &mut ctx.accounts.cohort;
+    cohort.take_seat()?;
+
+    // A waitlisted learner taking a freed seat gives up their waitlist place.
+    if let Some(waitlist_entry) = ctx.accounts.waitlist_entry.as_ref() {
+        cohort.leave_waitlist();
+        waitlist_entry.close(ctx.accounts.enroll.entry.learner.to_account_info())?;
+    }
+
+    // Cohort enrollments never outlive the cohort itself.
+    let enrollment = &mut ctx.accounts.enroll.enrollment;
+    enrollment.cohort = Some(cohort.key());
+    enrollment.expires_at = Some(
+        enrollment
+            .expires_at
+            .map_or(cohort.ends_at, |expires_at| expires_at.min(cohort.ends_at)),
+    );
+
+    emit!(CohortEnrolled {
+        learner: enrollment.learner,
+        course_id: enrollment.course_id.clone(),
+        cohort_id,
+        enrolled_count: cohort.enrolled_count,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(course_id: String, cohort_id: u32)]
+pub struct EnrollInCohort<'info> {
+    pub enroll: Enroll<'info>,
+    #[account(
+        mut,
+        seeds = [b"cohort", enroll.entry.course.key().as_ref(), &cohort_id.to_le_bytes()],
+        bump = cohort.bump,
+        constraint = cohort.course == enroll.entry.course.key() @ AcademyError::CohortMismatch,
+    )]
+    pub cohort: Account<'info, Cohort>,
+    #[account(
+        mut,
+        seeds = [b"cohort_waitlist", cohort.key().as_ref(), enroll.entry.learner.key().as_ref()],
+        bump = waitlist_entry.bump
+    )]
+    pub waitlist_entry: Option<Account<'info, CohortWaitlistEntry>>,
+}
+
+#[event]
+pub struct CohortEnrolled {
+    pub learner: Pubkey,
+    pub course_id: String,
+    pub cohort_id: u32,
+    pub enrolled_count: u32,
+}
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{Cohort, Course, Enrollment, EnrollmentArchive, ACCOUNT_RESERVED_LEN},
+    utils::{create_pda_account, id_seed, move_lamports, release_cohort_seat},
+};
+
+pub fn expire_enrollment(ctx: Context<ExpireEnrollment>) -> Result<()> {
+    let course = &mut ctx.accounts.course;
+    let enrollment = &ctx.accounts.enrollment;
+
+    require!(enrollment.course_id == course.course_id, AcademyError::InvalidCourseId);
+    require!(
+        enrollment.completed_at.is_none(),
+        AcademyError::CourseAlreadyFinalized
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        enrollment.is_expired(now),
+        AcademyError::EnrollmentNotExpired
+    );
+    require!(
+        enrollment.commitment_amount == 0,
+        AcademyError::CommitmentOutstanding
+    );
+
+    if enrollment.escrowed_amount > 0 {
+        course.release_revenue(enrollment.escrowed_amount)?;
+    }
+    release_cohort_seat(enrollment, ctx.accounts.cohort.as_mut())?;
+    record_expired_attempt(
+        enrollment,
+        &ctx.accounts.enrollment_archive,
+        &ctx.accounts.system_program,
+        ctx.bumps.enrollment_archive,
+        now,
+    )?;
+
+    emit!(EnrollmentExpired {
+        learner: enrollment.learner,
+        course_id: enrollment.course_id.clone(),
+        closed_by: ctx.accounts.cranker.key(),
+    });
+
+    Ok(())
+}
+
+// The cranker has no stake in the learner's history, so a missing archive is
+// paid for out of the expiring enrollment's rent; `close` then returns what is
+// left of it to the learner.
+fn record_expired_attempt<'info>(
+    enrollment: &Account<'info, Enrollment>,
+    archive_info: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    bump: u8,
+    now: i64,
+) -> Result<()> {
+    let mut archive = if archive_info.data_is_empty() {
+        let space = 8 + EnrollmentArchive::LEN;
+        let rent_due = Rent::get()?
+            .minimum_balance(space)
+            .saturating_sub(archive_info.lamports());
+        let enrollment_info = enrollment.to_account_info();
+        move_lamports(&enrollment_info, archive_info, rent_due)?;
+        // Fully funded above, so the payer is never charged.
+        create_pda_account(
+            archive_info,
+            &enrollment_info,
+            system_program,
+            space,
+            &[
+                b"enrollment_archive",
+                &id_seed(&enrollment.course_id)[..],
+                enrollment.learner.as_ref(),
+                &[bump],
+            ],
+        )?;
+        EnrollmentArchive {
+            course_id: String::new(),
+            learner: Pubkey::default(),
+            attempt_count: 0,
+            best_lesson_flags: [0; 4],
+            first_enrolled_at: 0,
+            last_enrolled_at: 0,
+            last_closed_at: 0,
+            completed_at: None,
+            bump: 0,
+            version: 0,
+            reserved: [0; ACCOUNT_RESERVED_LEN],
+        }
+    } else {
+        EnrollmentArchive::try_deserialize(&mut &archive_info.try_borrow_data()?[..])?
+    };
+
+    archive.record_attempt(enrollment, bump, now)?;
+    archive.try_serialize(&mut &mut archive_info.try_borrow_mut_data()?[..])
+}
+
+#[derive(Accounts)]
+pub struct ExpireEnrollment<'info> {
+    #[account(mut, seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
+    pub course: Account<'info, Course>,
+    #[account(
+        mut,
+        close = learner,
+        seeds = [b"enrollment", &id_seed(&enrollment.course_id)[..], learner.key().as_ref()],
+        bump = enrollment.bump
+    )]
+    pub enrollment: Account<'info, Enrollment>,
+    /// CHECK: The learner's `EnrollmentArchive` PDA; created here if missing.
+    #[account(
+        mut,
+        seeds = [
+            b"enrollment_archive",
+            &id_seed(&enrollment.course_id)[..],
+            learner.key().as_ref()
+        ],
+        bump
+    )]
+    pub enrollment_archive: UncheckedAccount<'info>,
+    /// CHECK: Original payer of the enrollment rent; bound by the enrollment seeds.
+    #[account(mut)]
+    pub learner: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub cohort: Option<Account<'info, Cohort>>,
+    pub cranker: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct EnrollmentExpired {
+    pub learner: Pubkey,
+    pub course_id: String,
+    pub closed_by: Pubkey,
+}
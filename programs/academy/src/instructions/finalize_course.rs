@@ -3,24 +3,47 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
     errors::AcademyError,
-    state::{Config, Course, Enrollment},
-    utils::mint_xp,
+    state::{
+        BackendSignerRole, Cohort, Config, Course, CreatorVault, Enrollment, Guild, Leaderboard,
+        LIFETIME_LEADERBOARD,
+    },
+    utils::{
+        id_seed, mint_xp, pay_out_commitment, record_guild_xp, record_leaderboards, CommitmentVault,
+    },
 };
 
 pub fn finalize_course(ctx: Context<FinalizeCourse>) -> Result<()> {
-    require_keys_eq!(
-        ctx.accounts.backend_signer.key(),
-        ctx.accounts.config.backend_signer,
-        AcademyError::BackendSignerMismatch
+    let stake_holder = ctx.accounts.enrollment.to_account_info();
+    process_finalize_course(
+        &mut ctx.accounts.finalize,
+        &mut ctx.accounts.enrollment,
+        Some(&stake_holder),
+        ctx.bumps.finalize.commitment_vault,
+    )
+}
+
+// Shared by `finalize_course`, `finalize_course_attested` and the compressed
+// build's `finalize_course`; the caller has already established that
+// `backend_signer_role` vouches for this completion and supplies the
+// enrollment, plus the account holding its SOL stake when it has one.
+pub(crate) fn process_finalize_course<'info>(
+    accounts: &mut CourseFinalization<'info>,
+    enrollment: &mut Enrollment,
+    stake_holder: Option<&AccountInfo<'info>>,
+    commitment_vault_bump: Option<u8>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        accounts.backend_signer_role.is_valid_at(now),
+        AcademyError::BackendSignerNotValid
     );
     require_keys_eq!(
-        ctx.accounts.xp_mint.key(),
-        ctx.accounts.config.xp_mint,
+        accounts.xp_mint.key(),
+        accounts.config.xp_mint,
         AcademyError::MintMismatch
     );
 
-    let course = &mut ctx.accounts.course;
-    let enrollment = &mut ctx.accounts.enrollment;
+    let course = &mut accounts.course;
 
     require!(course.is_active, AcademyError::CourseNotActive);
     require!(
@@ -29,9 +52,10 @@ pub fn finalize_course(ctx: Context<FinalizeCourse>) -> Result<()> {
     );
     require_keys_eq!(
         enrollment.learner,
-        ctx.accounts.learner.key(),
+        accounts.learner.key(),
         AcademyError::Unauthorized
     );
+    require!(!enrollment.is_expired(now), AcademyError::EnrollmentExpired);
     require!(
         enrollment.completed_at.is_none(),
         AcademyError::CourseAlreadyFinalized
@@ -42,69 +66,94 @@ pub fn finalize_course(ctx: Context<FinalizeCourse>) -> Result<()> {
     );
 
     require_keys_eq!(
-        ctx.accounts.learner_token_account.owner,
-        ctx.accounts.learner.key(),
+        accounts.learner_token_account.owner,
+        accounts.learner.key(),
         AcademyError::InvalidTokenAccount
     );
     require_keys_eq!(
-        ctx.accounts.learner_token_account.mint,
-        ctx.accounts.xp_mint.key(),
+        accounts.learner_token_account.mint,
+        accounts.xp_mint.key(),
         AcademyError::MintMismatch
     );
 
-    let per_lesson = u64::from(course.xp_per_lesson);
-    let lesson_count = u64::from(course.lesson_count);
-    let base_xp = per_lesson
-        .checked_mul(lesson_count)
-        .ok_or_else(|| error!(AcademyError::Overflow))?;
-    let bonus_xp = base_xp / 2;
+    let base_xp = course.base_xp()?;
+    let bonus_xp = course.completion_bonus_xp()?;
 
     mint_xp(
-        &ctx.accounts.config,
-        &ctx.accounts.xp_mint,
-        &ctx.accounts.learner_token_account,
-        &ctx.accounts.token_program,
+        &accounts.config,
+        &accounts.xp_mint,
+        &accounts.learner_token_account,
+        accounts
+            .season_mint
+            .as_ref()
+            .zip(accounts.learner_season_account.as_ref()),
+        &accounts.token_program,
         bonus_xp,
     )?;
+    record_leaderboards(
+        &accounts.config,
+        &mut accounts.leaderboard,
+        accounts.season_leaderboard.as_deref_mut(),
+        &accounts.learner_token_account,
+        accounts.learner_season_account.as_ref(),
+        bonus_xp,
+    )?;
+    record_guild_xp(
+        &accounts.guild_membership,
+        accounts.guild.as_deref_mut(),
+        bonus_xp,
+    )?;
+    accounts.backend_signer_role.record_mint(bonus_xp)?;
 
-    let new_completion_count = course
-        .completion_count
-        .checked_add(1)
-        .ok_or_else(|| error!(AcademyError::Overflow))?;
-    course.completion_count = new_completion_count;
-
-    let creator_xp = if new_completion_count >= course.min_completions_for_reward {
-        course.creator_reward_xp
-    } else {
-        0
-    };
+    let creator_xp = course.record_completion()?;
 
     if creator_xp > 0 {
-        require_keys_eq!(ctx.accounts.creator.key(), course.creator, AcademyError::Unauthorized);
-        require_keys_eq!(
-            ctx.accounts.creator_token_account.owner,
-            ctx.accounts.creator.key(),
-            AcademyError::InvalidTokenAccount
-        );
-        require_keys_eq!(
-            ctx.accounts.creator_token_account.mint,
-            ctx.accounts.xp_mint.key(),
-            AcademyError::MintMismatch
-        );
-
-        mint_xp(
-            &ctx.accounts.config,
-            &ctx.accounts.xp_mint,
-            &ctx.accounts.creator_token_account,
-            &ctx.accounts.token_program,
-            u64::from(creator_xp),
+        accounts.creator_vault.accrue(u64::from(creator_xp))?;
+    }
+
+    if enrollment.escrowed_amount > 0 {
+        course.release_revenue(enrollment.escrowed_amount)?;
+        enrollment.escrowed_amount = 0;
+    }
+
+    if enrollment.commitment_amount > 0 && now <= enrollment.commitment_deadline {
+        let amount = pay_out_commitment(
+            course,
+            enrollment,
+            stake_holder,
+            &accounts.learner.to_account_info(),
+            accounts.learner_commitment_account.as_ref(),
+            CommitmentVault {
+                vault: accounts
+                    .commitment_vault
+                    .as_ref()
+                    .zip(commitment_vault_bump),
+                mint: accounts.commitment_mint.as_ref(),
+                token_program: accounts.commitment_token_program.as_ref(),
+            },
         )?;
+        enrollment.commitment_amount = 0;
+
+        emit!(CommitmentReturned {
+            learner: accounts.learner.key(),
+            course_id: course.course_id.clone(),
+            amount,
+        });
+    }
+
+    if let Some(cohort_key) = enrollment.cohort {
+        let cohort = accounts
+            .cohort
+            .as_mut()
+            .ok_or_else(|| error!(AcademyError::CohortMismatch))?;
+        require_keys_eq!(cohort.key(), cohort_key, AcademyError::CohortMismatch);
+        cohort.record_completion()?;
     }
 
-    enrollment.completed_at = Some(Clock::get()?.unix_timestamp);
+    enrollment.completed_at = Some(now);
 
     emit!(CourseFinalized {
-        learner: ctx.accounts.learner.key(),
+        learner: accounts.learner.key(),
         course_id: course.course_id.clone(),
         total_xp: u32::try_from(base_xp).map_err(|_| error!(AcademyError::Overflow))?,
         bonus_xp: u32::try_from(bonus_xp).map_err(|_| error!(AcademyError::Overflow))?,
@@ -117,28 +166,76 @@ pub fn finalize_course(ctx: Context<FinalizeCourse>) -> Result<()> {
 
 #[derive(Accounts)]
 pub struct FinalizeCourse<'info> {
-    #[account(seeds = [b"config"], bump = config.bump)]
-    pub config: Account<'info, Config>,
-    #[account(mut, seeds = [b"course", course.course_id.as_bytes()], bump = course.bump)]
-    pub course: Account<'info, Course>,
+    pub finalize: CourseFinalization<'info>,
     #[account(
         mut,
-        seeds = [b"enrollment", enrollment.course_id.as_bytes(), learner.key().as_ref()],
+        seeds = [
+            b"enrollment",
+            &id_seed(&enrollment.course_id)[..],
+            finalize.learner.key().as_ref()
+        ],
         bump = enrollment.bump
     )]
     pub enrollment: Account<'info, Enrollment>,
+    #[account(address = finalize.backend_signer_role.signer @ AcademyError::Unauthorized)]
+    pub backend_signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CourseFinalization<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
+    pub course: Account<'info, Course>,
     /// CHECK: Learner pubkey used for ownership checks and enrollment seed validation.
+    #[account(mut)]
     pub learner: UncheckedAccount<'info>,
     #[account(mut)]
     pub learner_token_account: InterfaceAccount<'info, TokenAccount>,
-    #[account(mut)]
-    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
-    /// CHECK: Verified against `course.creator` in handler.
-    pub creator: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"creator_vault", course.key().as_ref()],
+        bump = creator_vault.bump
+    )]
+    pub creator_vault: Account<'info, CreatorVault>,
     #[account(mut)]
     pub xp_mint: InterfaceAccount<'info, Mint>,
-    pub backend_signer: Signer<'info>,
+    #[account(mut)]
+    pub season_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub learner_season_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &LIFETIME_LEADERBOARD.to_le_bytes()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &config.current_season.to_le_bytes()],
+        bump = season_leaderboard.bump
+    )]
+    pub season_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    /// CHECK: The recipient's `GuildMembership` PDA; may not exist.
+    #[account(seeds = [b"guild_membership", learner_token_account.owner.as_ref()], bump)]
+    pub guild_membership: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub guild: Option<Box<Account<'info, Guild>>>,
+    #[account(
+        mut,
+        seeds = [b"backend_signer", backend_signer_role.signer.as_ref()],
+        bump = backend_signer_role.bump
+    )]
+    pub backend_signer_role: Account<'info, BackendSignerRole>,
     pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut)]
+    pub learner_commitment_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, seeds = [b"commitment_vault", course.key().as_ref()], bump)]
+    pub commitment_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub commitment_mint: Option<InterfaceAccount<'info, Mint>>,
+    pub commitment_token_program: Option<Interface<'info, TokenInterface>>,
+    #[account(mut)]
+    pub cohort: Option<Account<'info, Cohort>>,
 }
 
 #[event]
@@ -150,3 +247,10 @@ pub struct CourseFinalized {
     pub creator: Pubkey,
     pub creator_xp: u32,
 }
+
+#[event]
+pub struct CommitmentReturned {
+    pub learner: Pubkey,
+    pub course_id: String,
+    pub amount: u64,
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::ID as INSTRUCTIONS_SYSVAR_ID;
+
+use crate::{
+    errors::AcademyError,
+    instructions::finalize_course::*,
+    state::{AttestationNonces, Enrollment, CURRENT_ACCOUNT_VERSION, FINALIZE_ATTESTATION_INDEX},
+    utils::{attestation_message, id_seed, verify_backend_attestation},
+};
+
+pub fn finalize_course_attested(
+    ctx: Context<FinalizeCourseAttested>,
+    nonce: u64,
+    expiry: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(now <= expiry, AcademyError::AttestationExpired);
+
+    let learner = ctx.accounts.learner.key();
+    let message = attestation_message(
+        &ctx.accounts.finalize.config,
+        &learner,
+        &ctx.accounts.finalize.course.key(),
+        FINALIZE_ATTESTATION_INDEX,
+        nonce,
+        expiry,
+    );
+    verify_backend_attestation(
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.finalize.backend_signer_role.signer,
+        &message,
+    )?;
+
+    let nonces = &mut ctx.accounts.attestation_nonces;
+    if nonces.learner == Pubkey::default() {
+        nonces.learner = learner;
+        nonces.bump = ctx.bumps.attestation_nonces;
+        nonces.version = CURRENT_ACCOUNT_VERSION;
+    }
+    nonces.consume(nonce)?;
+
+    let stake_holder = ctx.accounts.enrollment.to_account_info();
+    process_finalize_course(
+        &mut ctx.accounts.finalize,
+        &mut ctx.accounts.enrollment,
+        Some(&stake_holder),
+        ctx.bumps.finalize.commitment_vault,
+    )
+}
+
+#[derive(Accounts)]
+pub struct FinalizeCourseAttested<'info> {
+    pub finalize: CourseFinalization<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"enrollment",
+            &id_seed(&enrollment.course_id)[..],
+            finalize.learner.key().as_ref()
+        ],
+        bump = enrollment.bump
+    )]
+    pub enrollment: Account<'info, Enrollment>,
+    #[account(
+        init_if_needed,
+        payer = learner,
+        space = 8 + AttestationNonces::LEN,
+        seeds = [b"attestation_nonces", learner.key().as_ref()],
+        bump
+    )]
+    pub attestation_nonces: Account<'info, AttestationNonces>,
+    #[account(mut, address = finalize.learner.key() @ AcademyError::Unauthorized)]
+    pub learner: Signer<'info>,
+    /// CHECK: Instructions sysvar, address-checked; read to find the Ed25519 attestation.
+    #[account(address = INSTRUCTIONS_SYSVAR_ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
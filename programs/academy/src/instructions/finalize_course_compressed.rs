@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use light_sdk::{
+    account::sha::LightAccount,
+    cpi::{CpiAccounts, CpiInputs},
+    instruction::ValidityProof,
+};
+
+use crate::{
+    errors::AcademyError,
+    instructions::finalize_course::{process_finalize_course, CourseFinalization},
+    state::{CompressedEnrollment, Enrollment},
+    LIGHT_CPI_SIGNER,
+};
+
+// Compressed enrollments never hold a SOL stake (`enroll` refuses those
+// courses), so there is no stake holder to pay a commitment out of.
+pub fn finalize_course_compressed<'info>(
+    ctx: Context<'_, '_, '_, 'info, FinalizeCourseCompressed<'info>>,
+    proof: ValidityProof,
+    enrollment: CompressedEnrollment,
+) -> Result<()> {
+    let mut compressed_enrollment = LightAccount::<'_, Enrollment>::new_mut(
+        &crate::ID,
+        &enrollment.meta,
+        enrollment.enrollment,
+    )
+    .map_err(ProgramError::from)?;
+    process_finalize_course(
+        &mut ctx.accounts.finalize,
+        &mut compressed_enrollment,
+        None,
+        ctx.bumps.finalize.commitment_vault,
+    )?;
+
+    let cpi_accounts = CpiAccounts::new(
+        ctx.accounts.backend_signer.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+    CpiInputs::new(
+        proof,
+        vec![compressed_enrollment
+            .to_account_info()
+            .map_err(ProgramError::from)?],
+    )
+    .invoke_light_system_program(cpi_accounts)
+    .map_err(ProgramError::from)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FinalizeCourseCompressed<'info> {
+    pub finalize: CourseFinalization<'info>,
+    // Also pays the light system program's fees.
+    #[account(mut, address = finalize.backend_signer_role.signer @ AcademyError::Unauthorized)]
+    pub backend_signer: Signer<'info>,
+}
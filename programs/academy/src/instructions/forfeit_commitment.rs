@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    errors::AcademyError,
+    state::{Config, Course, Enrollment},
+    utils::{id_seed, pay_out_commitment, CommitmentVault},
+};
+
+pub fn forfeit_commitment(ctx: Context<ForfeitCommitment>) -> Result<()> {
+    let course = &ctx.accounts.course;
+    let enrollment = &ctx.accounts.enrollment;
+
+    require!(enrollment.course_id == course.course_id, AcademyError::InvalidCourseId);
+    require!(enrollment.commitment_amount > 0, AcademyError::NothingToClaim);
+    require!(
+        Clock::get()?.unix_timestamp > enrollment.commitment_deadline,
+        AcademyError::CommitmentDeadlineNotReached
+    );
+
+    let amount = pay_out_commitment(
+        course,
+        enrollment,
+        Some(&enrollment.to_account_info()),
+        &ctx.accounts.scholarship_pool.to_account_info(),
+        ctx.accounts.pool_token_account.as_ref(),
+        CommitmentVault {
+            vault: ctx
+                .accounts
+                .commitment_vault
+                .as_ref()
+                .zip(ctx.bumps.commitment_vault),
+            mint: ctx.accounts.commitment_mint.as_ref(),
+            token_program: ctx.accounts.token_program.as_ref(),
+        },
+    )?;
+    ctx.accounts.enrollment.commitment_amount = 0;
+
+    emit!(CommitmentForfeited {
+        learner: ctx.accounts.enrollment.learner,
+        course_id: ctx.accounts.course.course_id.clone(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ForfeitCommitment<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
+    pub course: Account<'info, Course>,
+    #[account(
+        mut,
+        seeds = [b"enrollment", &id_seed(&enrollment.course_id)[..], enrollment.learner.as_ref()],
+        bump = enrollment.bump
+    )]
+    pub enrollment: Account<'info, Enrollment>,
+    /// CHECK: Verified against `config.scholarship_pool`; receives SOL stakes directly.
+    #[account(mut, address = config.scholarship_pool @ AcademyError::TreasuryMismatch)]
+    pub scholarship_pool: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub pool_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, seeds = [b"commitment_vault", course.key().as_ref()], bump)]
+    pub commitment_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub commitment_mint: Option<InterfaceAccount<'info, Mint>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[event]
+pub struct CommitmentForfeited {
+    pub learner: Pubkey,
+    pub course_id: String,
+    pub amount: u64,
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, FreezeAccount, Mint, TokenAccount, TokenInterface};
+
+use crate::{errors::AcademyError, state::Config};
+
+pub fn freeze_learner_xp(ctx: Context<FreezeLearnerXp>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        ctx.accounts.config.authority,
+        AcademyError::Unauthorized
+    );
+    require_keys_eq!(
+        ctx.accounts.xp_mint.key(),
+        ctx.accounts.config.xp_mint,
+        AcademyError::MintMismatch
+    );
+
+    let signer_seeds: &[&[u8]] = &[b"config", &[ctx.accounts.config.bump]];
+    token_interface::freeze_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        FreezeAccount {
+            account: ctx.accounts.learner_token_account.to_account_info(),
+            mint: ctx.accounts.xp_mint.to_account_info(),
+            authority: ctx.accounts.config.to_account_info(),
+        },
+        &[signer_seeds],
+    ))?;
+
+    emit!(LearnerXpFrozen {
+        learner: ctx.accounts.learner_token_account.owner,
+        token_account: ctx.accounts.learner_token_account.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FreezeLearnerXp<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    pub xp_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub learner_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct LearnerXpFrozen {
+    pub learner: Pubkey,
+    pub token_account: Pubkey,
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{
+        Config, Course, CourseRole, GrantCourseRoleParams, COURSE_ROLE_ALL_PERMISSIONS,
+        CURRENT_ACCOUNT_VERSION,
+    },
+    utils::id_seed,
+};
+
+pub fn grant_course_role(
+    ctx: Context<GrantCourseRole>,
+    params: GrantCourseRoleParams,
+) -> Result<()> {
+    require!(
+        params.permissions != 0 && params.permissions & !COURSE_ROLE_ALL_PERMISSIONS == 0,
+        AcademyError::InvalidCourseRolePermissions
+    );
+
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        ctx.accounts.config.authority,
+        AcademyError::Unauthorized
+    );
+
+    let course_role = &mut ctx.accounts.course_role;
+    course_role.course = ctx.accounts.course.key();
+    course_role.holder = params.holder;
+    course_role.permissions = params.permissions;
+    course_role.max_xp_per_lesson = params.max_xp_per_lesson;
+    course_role.max_creator_reward_xp = params.max_creator_reward_xp;
+    course_role.granted_at = Clock::get()?.unix_timestamp;
+    course_role.bump = ctx.bumps.course_role;
+    course_role.version = CURRENT_ACCOUNT_VERSION;
+
+    emit!(CourseRoleGranted {
+        course_id: ctx.accounts.course.course_id.clone(),
+        holder: course_role.holder,
+        permissions: course_role.permissions,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: GrantCourseRoleParams)]
+pub struct GrantCourseRole<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
+    pub course: Account<'info, Course>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CourseRole::LEN,
+        seeds = [b"course_role", course.key().as_ref(), params.holder.as_ref()],
+        bump
+    )]
+    pub course_role: Account<'info, CourseRole>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct CourseRoleGranted {
+    pub course_id: String,
+    pub holder: Pubkey,
+    pub permissions: u8,
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    errors::AcademyError,
+    state::Course,
+    utils::id_seed,
+};
+
+pub fn init_commitment_vault(ctx: Context<InitCommitmentVault>) -> Result<()> {
+    let mint = ctx
+        .accounts
+        .course
+        .commitment
+        .and_then(|commitment| commitment.mint)
+        .ok_or_else(|| error!(AcademyError::CommitmentNotSpl))?;
+    require_keys_eq!(
+        ctx.accounts.commitment_mint.key(),
+        mint,
+        AcademyError::MintMismatch
+    );
+
+    emit!(CommitmentVaultInitialized {
+        course_id: ctx.accounts.course.course_id.clone(),
+        vault: ctx.accounts.commitment_vault.key(),
+        mint,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitCommitmentVault<'info> {
+    #[account(seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
+    pub course: Account<'info, Course>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"commitment_vault", course.key().as_ref()],
+        bump,
+        token::mint = commitment_mint,
+        token::authority = commitment_vault,
+        token::token_program = token_program,
+    )]
+    pub commitment_vault: InterfaceAccount<'info, TokenAccount>,
+    pub commitment_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct CommitmentVaultInitialized {
+    pub course_id: String,
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    errors::AcademyError,
+    state::Course,
+    utils::id_seed,
+};
+
+pub fn init_course_escrow(ctx: Context<InitCourseEscrow>) -> Result<()> {
+    let pricing = ctx
+        .accounts
+        .course
+        .pricing
+        .ok_or_else(|| error!(AcademyError::CourseNotPriced))?;
+    require_keys_eq!(
+        ctx.accounts.payment_mint.key(),
+        pricing.mint,
+        AcademyError::MintMismatch
+    );
+
+    emit!(CourseEscrowInitialized {
+        course_id: ctx.accounts.course.course_id.clone(),
+        escrow: ctx.accounts.course_escrow.key(),
+        mint: pricing.mint,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitCourseEscrow<'info> {
+    #[account(seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
+    pub course: Account<'info, Course>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"course_escrow", course.key().as_ref()],
+        bump,
+        token::mint = payment_mint,
+        token::authority = course_escrow,
+        token::token_program = token_program,
+    )]
+    pub course_escrow: InterfaceAccount<'info, TokenAccount>,
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct CourseEscrowInitialized {
+    pub course_id: String,
+    pub escrow: Pubkey,
+    pub mint: Pubkey,
+}
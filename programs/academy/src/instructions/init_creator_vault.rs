@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Course, CreatorVault},
+    utils::id_seed,
+};
+
+// Backfills the vault for courses created before creator vaults existed, so
+// they can be finalized again. Anyone may pay for it: the vault's contents
+// are fixed by the course.
+pub fn init_creator_vault(ctx: Context<InitCreatorVault>) -> Result<()> {
+    let course = &ctx.accounts.course;
+    ctx.accounts.creator_vault.set_inner(CreatorVault::new(
+        course.key(),
+        course.creator,
+        ctx.bumps.creator_vault,
+    ));
+
+    emit!(CreatorVaultInitialized {
+        course_id: course.course_id.clone(),
+        creator: course.creator,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitCreatorVault<'info> {
+    #[account(seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
+    pub course: Account<'info, Course>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CreatorVault::LEN,
+        seeds = [b"creator_vault", course.key().as_ref()],
+        bump
+    )]
+    pub creator_vault: Account<'info, CreatorVault>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct CreatorVaultInitialized {
+    pub course_id: String,
+    pub creator: Pubkey,
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{Config, Leaderboard, CURRENT_ACCOUNT_VERSION, LIFETIME_LEADERBOARD},
+};
+
+// Season boards are created by `create_season` and the lifetime one by
+// `initialize`. Deployments initialized before the lifetime board existed run
+// this once, before any XP-minting instruction, all of which expect it.
+pub fn init_leaderboard(ctx: Context<InitLeaderboard>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        ctx.accounts.config.authority,
+        AcademyError::Unauthorized
+    );
+
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    leaderboard.season = LIFETIME_LEADERBOARD;
+    leaderboard.count = 0;
+    leaderboard.updated_at = Clock::get()?.unix_timestamp;
+    leaderboard.bump = ctx.bumps.leaderboard;
+    leaderboard.version = CURRENT_ACCOUNT_VERSION;
+
+    emit!(LeaderboardInitialized {
+        leaderboard: leaderboard.key(),
+        season: LIFETIME_LEADERBOARD,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitLeaderboard<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Leaderboard::LEN,
+        seeds = [b"leaderboard".as_ref(), &LIFETIME_LEADERBOARD.to_le_bytes()],
+        bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct LeaderboardInitialized {
+    pub leaderboard: Pubkey,
+    pub season: u32,
+}
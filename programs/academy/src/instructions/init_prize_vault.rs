@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{errors::AcademyError, state::RewardItem, utils::id_seed};
+
+pub fn init_prize_vault(ctx: Context<InitPrizeVault>) -> Result<()> {
+    let payout = ctx
+        .accounts
+        .reward_item
+        .payout
+        .ok_or_else(|| error!(AcademyError::MissingPrizeAccounts))?;
+    require_keys_eq!(
+        ctx.accounts.prize_mint.key(),
+        payout.mint,
+        AcademyError::MintMismatch
+    );
+
+    emit!(PrizeVaultInitialized {
+        reward_id: ctx.accounts.reward_item.reward_id.clone(),
+        vault: ctx.accounts.prize_vault.key(),
+        mint: payout.mint,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitPrizeVault<'info> {
+    #[account(
+        seeds = [b"reward_item", &id_seed(&reward_item.reward_id)[..]],
+        bump = reward_item.bump
+    )]
+    pub reward_item: Account<'info, RewardItem>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"prize_vault", reward_item.key().as_ref()],
+        bump,
+        token::mint = prize_mint,
+        token::authority = prize_vault,
+        token::token_program = token_program,
+    )]
+    pub prize_vault: InterfaceAccount<'info, TokenAccount>,
+    pub prize_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct PrizeVaultInitialized {
+    pub reward_id: String,
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+}
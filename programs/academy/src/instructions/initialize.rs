@@ -1,40 +1,125 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{Mint, TokenInterface};
+use anchor_spl::token_2022::Token2022;
 
-use crate::{errors::AcademyError, state::{Config, I80F48, MinterRole, MAX_MINTER_LABEL_LEN}};
+use crate::{
+    errors::AcademyError,
+    state::{
+        BackendSignerRole, Config, InitializeParams, Leaderboard, MinterRole,
+        CURRENT_ACCOUNT_VERSION, I80F48, LIFETIME_LEADERBOARD, MAX_METADATA_URI_LEN,
+        MAX_MINTER_LABEL_LEN, MAX_TOKEN_NAME_LEN, MAX_TOKEN_SYMBOL_LEN,
+    },
+    utils::{create_xp_mint, XpMintMetadata},
+};
+
+pub fn initialize(ctx: Context<Initialize>, params: InitializeParams) -> Result<()> {
+    require!(
+        !params.xp_name.is_empty() && params.xp_name.len() <= MAX_TOKEN_NAME_LEN,
+        AcademyError::InvalidMetadata
+    );
+    require!(
+        !params.xp_symbol.is_empty() && params.xp_symbol.len() <= MAX_TOKEN_SYMBOL_LEN,
+        AcademyError::InvalidMetadata
+    );
+    require!(
+        params.xp_uri.len() <= MAX_METADATA_URI_LEN,
+        AcademyError::InvalidMetadata
+    );
+
+    create_xp_mint(
+        &ctx.accounts.xp_mint,
+        &ctx.accounts.config.to_account_info(),
+        ctx.bumps.config,
+        &ctx.accounts.authority,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        XpMintMetadata {
+            name: &params.xp_name,
+            symbol: &params.xp_symbol,
+            uri: &params.xp_uri,
+        },
+    )?;
 
-pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
     let config = &mut ctx.accounts.config;
     let authority = ctx.accounts.authority.key();
+    let backend_signer = params.backend_signer;
 
     config.authority = authority;
-    config.backend_signer = authority;
+    config.backend_signer_count = 1;
     config.xp_mint = ctx.accounts.xp_mint.key();
+    config.treasury = authority;
+    config.submission_deposit = 0;
+    config.rejection_slash_bps = 0;
+    config.scholarship_pool = authority;
     config.bump = ctx.bumps.config;
+    config.version = CURRENT_ACCOUNT_VERSION;
+    config.current_season = 0;
+    config.season_mint = Pubkey::default();
+    config.season_active = false;
 
     let minter_role = &mut ctx.accounts.backend_minter_role;
-    minter_role.minter = authority;
+    minter_role.minter = backend_signer;
     minter_role.label = "backend".to_string();
     require!(
         minter_role.label.len() <= MAX_MINTER_LABEL_LEN,
         AcademyError::InvalidMetadata
     );
-    minter_role.max_xp_per_call = I80F48 { value: i128::MAX };
-    minter_role.total_xp_minted = I80F48 { value: 0 };
+    minter_role.max_xp_per_call = I80F48::MAX;
+    minter_role.total_xp_minted = I80F48::ZERO;
+    minter_role.max_xp_per_window = I80F48::ZERO;
+    minter_role.window_secs = 0;
+    minter_role.window_start = 0;
+    minter_role.window_minted = I80F48::ZERO;
     minter_role.is_active = true;
     minter_role.created_at = Clock::get()?.unix_timestamp;
     minter_role.bump = ctx.bumps.backend_minter_role;
+    minter_role.version = CURRENT_ACCOUNT_VERSION;
+
+    let signer_role = &mut ctx.accounts.backend_signer_role;
+    signer_role.set_inner(BackendSignerRole::new(
+        backend_signer,
+        "backend".to_string(),
+        minter_role.created_at,
+        i64::MAX,
+        minter_role.created_at,
+        ctx.bumps.backend_signer_role,
+    ));
+
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    leaderboard.season = LIFETIME_LEADERBOARD;
+    leaderboard.count = 0;
+    leaderboard.updated_at = minter_role.created_at;
+    leaderboard.bump = ctx.bumps.leaderboard;
+    leaderboard.version = CURRENT_ACCOUNT_VERSION;
 
     emit!(ConfigUpdated { authority });
     emit!(crate::instructions::register_minter::MinterRegistered {
-        minter: authority,
+        minter: backend_signer,
         label: minter_role.label.clone(),
     });
+    emit!(crate::instructions::add_backend_signer::BackendSignerAdded {
+        signer: backend_signer,
+        label: signer_role.label.clone(),
+        valid_from: signer_role.valid_from,
+        valid_until: signer_role.valid_until,
+    });
+
+    emit!(crate::instructions::init_leaderboard::LeaderboardInitialized {
+        leaderboard: leaderboard.key(),
+        season: LIFETIME_LEADERBOARD,
+    });
+
+    emit!(XpMintCreated {
+        mint: ctx.accounts.xp_mint.key(),
+        name: params.xp_name,
+        symbol: params.xp_symbol,
+        uri: params.xp_uri,
+    });
 
     Ok(())
 }
 
 #[derive(Accounts)]
+#[instruction(params: InitializeParams)]
 pub struct Initialize<'info> {
     #[account(
         init,
@@ -44,30 +129,47 @@ pub struct Initialize<'info> {
         bump
     )]
     pub config: Account<'info, Config>,
-    #[account(
-        init,
-        payer = authority,
-        mint::decimals = 0,
-        mint::authority = config,
-        mint::freeze_authority = config,
-        mint::token_program = token_program,
-    )]
-    pub xp_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub xp_mint: Signer<'info>,
     #[account(mut)]
     pub authority: Signer<'info>,
     #[account(
         init,
         payer = authority,
         space = 8 + MinterRole::LEN,
-        seeds = [b"minter", authority.key().as_ref()],
+        seeds = [b"minter", params.backend_signer.as_ref()],
         bump
     )]
     pub backend_minter_role: Account<'info, MinterRole>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + BackendSignerRole::LEN,
+        seeds = [b"backend_signer", params.backend_signer.as_ref()],
+        bump
+    )]
+    pub backend_signer_role: Account<'info, BackendSignerRole>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Leaderboard::LEN,
+        seeds = [b"leaderboard".as_ref(), &LIFETIME_LEADERBOARD.to_le_bytes()],
+        bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
     pub system_program: Program<'info, System>,
-    pub token_program: Interface<'info, TokenInterface>,
+    pub token_program: Program<'info, Token2022>,
 }
 
 #[event]
 pub struct ConfigUpdated {
     pub authority: Pubkey,
 }
+
+#[event]
+pub struct XpMintCreated {
+    pub mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::AcademyError, state::{Config, Course, Enrollment, I80F48}};
+use crate::{
+    errors::AcademyError,
+    state::{BackendSignerRole, CompletionRecord, Config, Course, Enrollment, I80F48},
+    utils::id_seed,
+};
 
 pub fn issue_credential(
     ctx: Context<IssueCredential>,
@@ -9,28 +13,35 @@ pub fn issue_credential(
     _courses_completed: u32,
     _total_xp: I80F48,
 ) -> Result<()> {
-    require_keys_eq!(
-        ctx.accounts.backend_signer.key(),
-        ctx.accounts.config.backend_signer,
-        AcademyError::BackendSignerMismatch
-    );
-
-    let enrollment = &mut ctx.accounts.enrollment;
-    require!(
-        enrollment.course_id == ctx.accounts.course.course_id,
-        AcademyError::InvalidCourseId
-    );
-    require_keys_eq!(
-        enrollment.learner,
-        ctx.accounts.learner.key(),
-        AcademyError::Unauthorized
-    );
     require!(
-        enrollment.completed_at.is_some(),
-        AcademyError::CourseNotFinalized
+        ctx.accounts
+            .backend_signer_role
+            .is_valid_at(Clock::get()?.unix_timestamp),
+        AcademyError::BackendSignerNotValid
     );
 
-    enrollment.credential_asset = Some(ctx.accounts.credential_asset.key());
+    let credential_asset = ctx.accounts.credential_asset.key();
+    if let Some(enrollment) = ctx.accounts.enrollment.as_mut() {
+        require!(
+            enrollment.course_id == ctx.accounts.course.course_id,
+            AcademyError::InvalidCourseId
+        );
+        require_keys_eq!(
+            enrollment.learner,
+            ctx.accounts.learner.key(),
+            AcademyError::Unauthorized
+        );
+        require!(
+            enrollment.completed_at.is_some(),
+            AcademyError::CourseNotFinalized
+        );
+        enrollment.credential_asset = Some(credential_asset);
+    } else if let Some(completion_record) = ctx.accounts.completion_record.as_mut() {
+        // Seeds already bind the record to this course and learner.
+        completion_record.credential = Some(credential_asset);
+    } else {
+        return err!(AcademyError::MissingCompletionProof);
+    }
 
     // Metaplex Core mint CPI is intentionally left as integration hook.
     // Account wiring matches the spec so this can be swapped to a CPI call.
@@ -47,15 +58,21 @@ pub fn issue_credential(
 pub struct IssueCredential<'info> {
     #[account(seeds = [b"config"], bump = config.bump)]
     pub config: Account<'info, Config>,
-    #[account(seeds = [b"course", course.course_id.as_bytes()], bump = course.bump)]
+    #[account(seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
     pub course: Account<'info, Course>,
     #[account(
         mut,
-        seeds = [b"enrollment", enrollment.course_id.as_bytes(), learner.key().as_ref()],
+        seeds = [b"enrollment", &id_seed(&course.course_id)[..], learner.key().as_ref()],
         bump = enrollment.bump
     )]
-    pub enrollment: Account<'info, Enrollment>,
-    /// CHECK: Learner pubkey used for ownership checks and enrollment seed validation.
+    pub enrollment: Option<Account<'info, Enrollment>>,
+    #[account(
+        mut,
+        seeds = [b"completion", course.key().as_ref(), learner.key().as_ref()],
+        bump = completion_record.bump
+    )]
+    pub completion_record: Option<Account<'info, CompletionRecord>>,
+    /// CHECK: Learner pubkey used for ownership checks and enrollment/record seed validation.
     pub learner: UncheckedAccount<'info>,
     #[account(mut)]
     pub credential_asset: Signer<'info>,
@@ -63,6 +80,11 @@ pub struct IssueCredential<'info> {
     pub track_collection: UncheckedAccount<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"backend_signer", backend_signer.key().as_ref()],
+        bump = backend_signer_role.bump
+    )]
+    pub backend_signer_role: Account<'info, BackendSignerRole>,
     pub backend_signer: Signer<'info>,
     /// CHECK: Metaplex Core program account passed through to integration CPI.
     pub mpl_core_program: UncheckedAccount<'info>,
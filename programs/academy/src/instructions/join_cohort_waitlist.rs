@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{Cohort, CohortWaitlistEntry, Course, CURRENT_ACCOUNT_VERSION},
+    utils::id_seed,
+};
+
+pub fn join_cohort_waitlist(ctx: Context<JoinCohortWaitlist>) -> Result<()> {
+    let cohort = &mut ctx.accounts.cohort;
+
+    require!(cohort.waitlist_enabled, AcademyError::WaitlistDisabled);
+    require!(
+        Clock::get()?.unix_timestamp < cohort.ends_at,
+        AcademyError::CohortEnded
+    );
+    require!(cohort.is_full(), AcademyError::CohortNotFull);
+
+    cohort.waitlist_count = cohort
+        .waitlist_count
+        .checked_add(1)
+        .ok_or_else(|| error!(AcademyError::Overflow))?;
+
+    let entry = &mut ctx.accounts.waitlist_entry;
+    entry.cohort = cohort.key();
+    entry.learner = ctx.accounts.learner.key();
+    entry.joined_at = Clock::get()?.unix_timestamp;
+    entry.bump = ctx.bumps.waitlist_entry;
+    entry.version = CURRENT_ACCOUNT_VERSION;
+
+    emit!(CohortWaitlistJoined {
+        learner: ctx.accounts.learner.key(),
+        course_id: ctx.accounts.course.course_id.clone(),
+        cohort_id: cohort.cohort_id,
+        waitlist_count: cohort.waitlist_count,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct JoinCohortWaitlist<'info> {
+    #[account(seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
+    pub course: Account<'info, Course>,
+    #[account(
+        mut,
+        seeds = [b"cohort", course.key().as_ref(), &cohort.cohort_id.to_le_bytes()],
+        bump = cohort.bump,
+        constraint = cohort.course == course.key() @ AcademyError::CohortMismatch,
+    )]
+    pub cohort: Account<'info, Cohort>,
+    #[account(
+        init,
+        payer = learner,
+        space = 8 + CohortWaitlistEntry::LEN,
+        seeds = [b"cohort_waitlist", cohort.key().as_ref(), learner.key().as_ref()],
+        bump
+    )]
+    pub waitlist_entry: Account<'info, CohortWaitlistEntry>,
+    #[account(mut)]
+    pub learner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct CohortWaitlistJoined {
+    pub learner: Pubkey,
+    pub course_id: String,
+    pub cohort_id: u32,
+    pub waitlist_count: u32,
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Guild, GuildMembership, CURRENT_ACCOUNT_VERSION},
+    utils::id_seed,
+};
+
+pub fn join_guild(ctx: Context<JoinGuild>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let learner = ctx.accounts.learner.key();
+
+    let membership = &mut ctx.accounts.guild_membership;
+    if membership.version == 0 {
+        membership.learner = learner;
+        membership.bump = ctx.bumps.guild_membership;
+        membership.version = CURRENT_ACCOUNT_VERSION;
+    }
+    membership.join(&mut ctx.accounts.guild, now)?;
+
+    emit!(GuildJoined {
+        guild_id: ctx.accounts.guild.guild_id.clone(),
+        learner,
+        member_count: ctx.accounts.guild.member_count,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct JoinGuild<'info> {
+    #[account(
+        mut,
+        seeds = [b"guild", &id_seed(&guild.guild_id)[..]],
+        bump = guild.bump
+    )]
+    pub guild: Account<'info, Guild>,
+    #[account(
+        init_if_needed,
+        payer = learner,
+        space = 8 + GuildMembership::LEN,
+        seeds = [b"guild_membership", learner.key().as_ref()],
+        bump
+    )]
+    pub guild_membership: Account<'info, GuildMembership>,
+    #[account(mut)]
+    pub learner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct GuildJoined {
+    pub guild_id: String,
+    pub learner: Pubkey,
+    pub member_count: u32,
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{Cohort, CohortWaitlistEntry, Course},
+    utils::id_seed,
+};
+
+pub fn leave_cohort_waitlist(ctx: Context<LeaveCohortWaitlist>) -> Result<()> {
+    let cohort = &mut ctx.accounts.cohort;
+    cohort.leave_waitlist();
+
+    emit!(CohortWaitlistLeft {
+        learner: ctx.accounts.learner.key(),
+        course_id: ctx.accounts.course.course_id.clone(),
+        cohort_id: cohort.cohort_id,
+        waitlist_count: cohort.waitlist_count,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct LeaveCohortWaitlist<'info> {
+    #[account(seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
+    pub course: Account<'info, Course>,
+    #[account(
+        mut,
+        seeds = [b"cohort", course.key().as_ref(), &cohort.cohort_id.to_le_bytes()],
+        bump = cohort.bump,
+        constraint = cohort.course == course.key() @ AcademyError::CohortMismatch,
+    )]
+    pub cohort: Account<'info, Cohort>,
+    #[account(
+        mut,
+        close = learner,
+        seeds = [b"cohort_waitlist", cohort.key().as_ref(), learner.key().as_ref()],
+        bump = waitlist_entry.bump
+    )]
+    pub waitlist_entry: Account<'info, CohortWaitlistEntry>,
+    #[account(mut)]
+    pub learner: Signer<'info>,
+}
+
+#[event]
+pub struct CohortWaitlistLeft {
+    pub learner: Pubkey,
+    pub course_id: String,
+    pub cohort_id: u32,
+    pub waitlist_count: u32,
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{Guild, GuildMembership},
+    utils::id_seed,
+};
+
+// XP already earned stays in the guild's total; the switch cooldown starts now.
+pub fn leave_guild(ctx: Context<LeaveGuild>) -> Result<()> {
+    let guild = &mut ctx.accounts.guild;
+    let membership = &mut ctx.accounts.guild_membership;
+    require_keys_eq!(membership.guild, guild.key(), AcademyError::NotInGuild);
+    membership.leave(guild, Clock::get()?.unix_timestamp)?;
+
+    emit!(GuildLeft {
+        guild_id: guild.guild_id.clone(),
+        learner: membership.learner,
+        member_count: guild.member_count,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct LeaveGuild<'info> {
+    #[account(
+        mut,
+        seeds = [b"guild", &id_seed(&guild.guild_id)[..]],
+        bump = guild.bump
+    )]
+    pub guild: Account<'info, Guild>,
+    #[account(
+        mut,
+        seeds = [b"guild_membership", learner.key().as_ref()],
+        bump = guild_membership.bump
+    )]
+    pub guild_membership: Account<'info, GuildMembership>,
+    pub learner: Signer<'info>,
+}
+
+#[event]
+pub struct GuildLeft {
+    pub guild_id: String,
+    pub learner: Pubkey,
+    pub member_count: u32,
+}
@@ -0,0 +1,217 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+
+use crate::{
+    errors::AcademyError,
+    state::{
+        AchievementReceipt, AchievementType, AttestationNonces, BackendSignerRole, Cohort,
+        CohortWaitlistEntry, CompletionRecord, Config, Course, CourseRole, CourseSubmission,
+        CreatorVault, Enrollment, EnrollmentArchive, Guild, GuildMembership, Leaderboard,
+        LegacyConfigV0, MentorReview, MentorRole, MinterRole, Redemption, RedemptionTally,
+        ReviewerRole, RewardDistribution, RewardItem, Season, Versioned, CURRENT_ACCOUNT_VERSION,
+    },
+    utils::create_pda_account,
+};
+
+pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+    let discriminator: [u8; 8] = {
+        let data = ctx.accounts.account.try_borrow_data()?;
+        require!(data.len() >= 8, AcademyError::AccountNotMigratable);
+        data[..8]
+            .try_into()
+            .map_err(|_| error!(AcademyError::AccountNotMigratable))?
+    };
+
+    let authority = config_authority(&ctx.accounts.config)?;
+    let migrate_as: fn(&MigrateAccount, Pubkey) -> Result<u8> = match discriminator {
+        d if d == Config::DISCRIMINATOR => migrate_config,
+        d if d == BackendSignerRole::DISCRIMINATOR => migrate::<BackendSignerRole>,
+        d if d == Course::DISCRIMINATOR => migrate::<Course>,
+        d if d == CreatorVault::DISCRIMINATOR => migrate::<CreatorVault>,
+        d if d == CourseSubmission::DISCRIMINATOR => migrate::<CourseSubmission>,
+        d if d == ReviewerRole::DISCRIMINATOR => migrate::<ReviewerRole>,
+        d if d == Enrollment::DISCRIMINATOR => migrate::<Enrollment>,
+        d if d == EnrollmentArchive::DISCRIMINATOR => migrate::<EnrollmentArchive>,
+        d if d == CompletionRecord::DISCRIMINATOR => migrate::<CompletionRecord>,
+        d if d == CourseRole::DISCRIMINATOR => migrate::<CourseRole>,
+        d if d == Cohort::DISCRIMINATOR => migrate::<Cohort>,
+        d if d == CohortWaitlistEntry::DISCRIMINATOR => migrate::<CohortWaitlistEntry>,
+        d if d == MinterRole::DISCRIMINATOR => migrate::<MinterRole>,
+        d if d == AchievementType::DISCRIMINATOR => migrate::<AchievementType>,
+        d if d == AchievementReceipt::DISCRIMINATOR => migrate::<AchievementReceipt>,
+        d if d == AttestationNonces::DISCRIMINATOR => migrate::<AttestationNonces>,
+        d if d == Season::DISCRIMINATOR => migrate::<Season>,
+        d if d == Leaderboard::DISCRIMINATOR => migrate::<Leaderboard>,
+        d if d == RewardDistribution::DISCRIMINATOR => migrate::<RewardDistribution>,
+        d if d == Guild::DISCRIMINATOR => migrate::<Guild>,
+        d if d == GuildMembership::DISCRIMINATOR => migrate::<GuildMembership>,
+        d if d == MentorRole::DISCRIMINATOR => migrate::<MentorRole>,
+        d if d == MentorReview::DISCRIMINATOR => migrate::<MentorReview>,
+        d if d == RewardItem::DISCRIMINATOR => migrate::<RewardItem>,
+        d if d == RedemptionTally::DISCRIMINATOR => migrate::<RedemptionTally>,
+        d if d == Redemption::DISCRIMINATOR => migrate::<Redemption>,
+        _ => return err!(AcademyError::AccountNotMigratable),
+    };
+    let from_version = migrate_as(ctx.accounts, authority)?;
+
+    emit!(AccountMigrated {
+        account: ctx.accounts.account.key(),
+        from_version,
+        to_version: CURRENT_ACCOUNT_VERSION,
+        migrated_by: ctx.accounts.migrator.key(),
+    });
+
+    Ok(())
+}
+
+// Read straight from the raw data: `authority` is the first field in every
+// layout, and the config itself may be the account awaiting migration.
+pub(crate) fn config_authority(config: &UncheckedAccount) -> Result<Pubkey> {
+    let data = config.try_borrow_data()?;
+    require!(
+        data.len() >= 8 + 32 && data[..8] == Config::DISCRIMINATOR,
+        AcademyError::AccountNotMigratable
+    );
+    Ok(Pubkey::new_from_array(
+        data[8..40]
+            .try_into()
+            .map_err(|_| error!(AcademyError::AccountNotMigratable))?,
+    ))
+}
+
+// The first config held a single `backend_signer` key; it is carried over as
+// an open-ended `BackendSignerRole` so the existing backend keeps signing.
+fn migrate_config(accounts: &MigrateAccount, authority: Pubkey) -> Result<u8> {
+    let legacy = {
+        let data = accounts.account.try_borrow_data()?;
+        if data.len() == 8 + LegacyConfigV0::LEN {
+            Some(
+                LegacyConfigV0::deserialize(&mut &data[8..])
+                    .map_err(|_| error!(AcademyError::AccountNotMigratable))?,
+            )
+        } else {
+            None
+        }
+    };
+    let from_version = migrate::<Config>(accounts, authority)?;
+    let Some(legacy) = legacy else {
+        return Ok(from_version);
+    };
+
+    let signer_role = accounts
+        .backend_signer_role
+        .as_ref()
+        .ok_or_else(|| error!(AcademyError::MissingBackendSignerRole))?;
+    let (role_key, role_bump) = Pubkey::find_program_address(
+        &[b"backend_signer", legacy.backend_signer.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        signer_role.key(),
+        role_key,
+        AcademyError::MissingBackendSignerRole
+    );
+    create_pda_account(
+        &signer_role.to_account_info(),
+        &accounts.migrator.to_account_info(),
+        &accounts.system_program.to_account_info(),
+        BackendSignerRole::SPACE,
+        &[b"backend_signer", legacy.backend_signer.as_ref(), &[role_bump]],
+    )?;
+    BackendSignerRole::new(
+        legacy.backend_signer,
+        "backend".to_string(),
+        0,
+        i64::MAX,
+        Clock::get()?.unix_timestamp,
+        role_bump,
+    )
+    .try_serialize(&mut &mut signer_role.try_borrow_mut_data()?[..])?;
+
+    let account_info = accounts.account.to_account_info();
+    let mut config = Config::try_deserialize(&mut &account_info.try_borrow_data()?[..])?;
+    config.backend_signer_count = 1;
+    config.try_serialize(&mut &mut account_info.try_borrow_mut_data()?[..])?;
+
+    Ok(from_version)
+}
+
+// Accounts written before versioning are decoded through their `Legacy*V0`
+// layout and come out as version 0; anything else must already be at the
+// current size, since later fields only ever came out of `reserved`.
+fn migrate<T: Versioned>(accounts: &MigrateAccount, authority: Pubkey) -> Result<u8> {
+    let account_info = accounts.account.to_account_info();
+
+    let mut account = {
+        let data = account_info.try_borrow_data()?;
+        match T::from_legacy(&data)? {
+            Some(account) => account,
+            None => {
+                require!(data.len() >= T::SPACE, AcademyError::AccountNotMigratable);
+                T::try_deserialize(&mut &data[..])?
+            }
+        }
+    };
+
+    let from_version = account.version();
+    require!(
+        from_version < CURRENT_ACCOUNT_VERSION,
+        AcademyError::AccountAlreadyMigrated
+    );
+    let migrator = accounts.migrator.key();
+    require!(
+        migrator == authority || account.owner() == Some(migrator),
+        AcademyError::Unauthorized
+    );
+
+    if account_info.data_len() < T::SPACE {
+        let rent_due = Rent::get()?
+            .minimum_balance(T::SPACE)
+            .saturating_sub(account_info.lamports());
+        if rent_due > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: accounts.migrator.to_account_info(),
+                        to: account_info.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+        account_info.realloc(T::SPACE, true)?;
+    }
+
+    account.upgrade(from_version)?;
+    account.set_version(CURRENT_ACCOUNT_VERSION);
+    account.try_serialize(&mut &mut account_info.try_borrow_mut_data()?[..])?;
+
+    Ok(from_version)
+}
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: Config PDA, address-checked; only `authority` is read from it.
+    #[account(seeds = [b"config"], bump)]
+    pub config: UncheckedAccount<'info>,
+    /// CHECK: Any program-owned account; its type is resolved from the discriminator.
+    #[account(mut, owner = crate::ID @ AcademyError::AccountNotMigratable)]
+    pub account: UncheckedAccount<'info>,
+    /// CHECK: The legacy backend signer's `backend_signer` PDA, address-checked
+    /// when created; only read when migrating a pre-versioning config.
+    #[account(mut)]
+    pub backend_signer_role: Option<UncheckedAccount<'info>>,
+    #[account(mut)]
+    pub migrator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub migrated_by: Pubkey,
+}
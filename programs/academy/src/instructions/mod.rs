@@ -14,6 +14,65 @@ pub mod issue_credential;
 pub mod upgrade_credential;
 pub mod reward_xp;
 pub mod award_achievement;
+pub mod grant_course_role;
+pub mod revoke_course_role;
+pub mod update_course_with_role;
+pub mod register_reviewer;
+pub mod revoke_reviewer;
+pub mod submit_course;
+pub mod approve_course;
+pub mod reject_course;
+pub mod claim_creator_reward;
+pub mod set_creator_splits;
+pub mod complete_lesson_attested;
+pub mod finalize_course_attested;
+pub mod add_backend_signer;
+pub mod update_backend_signer;
+pub mod remove_backend_signer;
+pub mod complete_lessons;
+pub mod complete_lessons_attested;
+pub mod batch_reward_xp;
+pub mod init_course_escrow;
+pub mod refund_enrollment;
+pub mod withdraw_course_revenue;
+pub mod init_commitment_vault;
+pub mod forfeit_commitment;
+pub mod expire_enrollment;
+pub mod create_cohort;
+pub mod enroll_in_cohort;
+pub mod join_cohort_waitlist;
+pub mod compact_enrollment;
+pub mod migrate_account;
+pub mod freeze_learner_xp;
+pub mod thaw_learner_xp;
+pub mod clawback_xp;
+pub mod create_reward_item;
+pub mod deactivate_reward_item;
+pub mod init_prize_vault;
+pub mod redeem_reward;
+pub mod create_season;
+pub mod close_season;
+pub mod init_leaderboard;
+pub mod create_distribution;
+pub mod claim_distribution;
+pub mod clawback_distribution;
+pub mod create_guild;
+pub mod join_guild;
+pub mod leave_guild;
+pub mod register_mentor;
+pub mod revoke_mentor;
+pub mod complete_lesson_mentor;
+pub mod init_creator_vault;
+pub mod leave_cohort_waitlist;
+pub mod rehome_account;
+#[cfg(feature = "light")]
+pub mod enroll_compressed;
+#[cfg(feature = "light")]
+pub mod complete_lesson_compressed;
+#[cfg(feature = "light")]
+pub mod finalize_course_compressed;
+#[cfg(feature = "light")]
+pub mod award_achievement_compressed;
 
 pub use initialize::*;
 pub use update_config::*;
@@ -31,3 +90,62 @@ pub use issue_credential::*;
 pub use upgrade_credential::*;
 pub use reward_xp::*;
 pub use award_achievement::*;
+pub use grant_course_role::*;
+pub use revoke_course_role::*;
+pub use update_course_with_role::*;
+pub use register_reviewer::*;
+pub use revoke_reviewer::*;
+pub use submit_course::*;
+pub use approve_course::*;
+pub use reject_course::*;
+pub use claim_creator_reward::*;
+pub use set_creator_splits::*;
+pub use complete_lesson_attested::*;
+pub use finalize_course_attested::*;
+pub use add_backend_signer::*;
+pub use update_backend_signer::*;
+pub use remove_backend_signer::*;
+pub use complete_lessons::*;
+pub use complete_lessons_attested::*;
+pub use batch_reward_xp::*;
+pub use init_course_escrow::*;
+pub use refund_enrollment::*;
+pub use withdraw_course_revenue::*;
+pub use init_commitment_vault::*;
+pub use forfeit_commitment::*;
+pub use expire_enrollment::*;
+pub use create_cohort::*;
+pub use enroll_in_cohort::*;
+pub use join_cohort_waitlist::*;
+pub use compact_enrollment::*;
+pub use migrate_account::*;
+pub use freeze_learner_xp::*;
+pub use thaw_learner_xp::*;
+pub use clawback_xp::*;
+pub use create_reward_item::*;
+pub use deactivate_reward_item::*;
+pub use init_prize_vault::*;
+pub use redeem_reward::*;
+pub use create_season::*;
+pub use close_season::*;
+pub use init_leaderboard::*;
+pub use create_distribution::*;
+pub use claim_distribution::*;
+pub use clawback_distribution::*;
+pub use create_guild::*;
+pub use join_guild::*;
+pub use leave_guild::*;
+pub use register_mentor::*;
+pub use revoke_mentor::*;
+pub use complete_lesson_mentor::*;
+pub use init_creator_vault::*;
+pub use leave_cohort_waitlist::*;
+pub use rehome_account::*;
+#[cfg(feature = "light")]
+pub use enroll_compressed::*;
+#[cfg(feature = "light")]
+pub use complete_lesson_compressed::*;
+#[cfg(feature = "light")]
+pub use finalize_course_compressed::*;
+#[cfg(feature = "light")]
+pub use award_achievement_compressed::*;
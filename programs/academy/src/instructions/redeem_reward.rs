@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    errors::AcademyError,
+    state::{
+        Config, Leaderboard, Redemption, RedemptionTally, RewardItem, CURRENT_ACCOUNT_VERSION,
+        LIFETIME_LEADERBOARD,
+    },
+    utils::{id_seed, transfer_from_vault},
+};
+
+pub fn redeem_reward(ctx: Context<RedeemReward>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let reward_item = &ctx.accounts.reward_item;
+    require!(
+        reward_item.is_available(now),
+        AcademyError::RewardNotAvailable
+    );
+    require!(
+        reward_item.redeemed_count < reward_item.max_supply,
+        AcademyError::RewardSoldOut
+    );
+    require!(
+        ctx.accounts.redemption_tally.count < reward_item.per_learner_limit,
+        AcademyError::RedemptionLimitReached
+    );
+
+    // XP is non-transferable, so spending it means the learner burns it.
+    token_interface::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.xp_mint.to_account_info(),
+                from: ctx.accounts.learner_xp_account.to_account_info(),
+                authority: ctx.accounts.learner.to_account_info(),
+            },
+        ),
+        reward_item.price_xp,
+    )?;
+
+    let payout_amount = match reward_item.payout {
+        Some(payout) => {
+            let (
+                Some(prize_vault),
+                Some(prize_mint),
+                Some(learner_prize_account),
+                Some(prize_token_program),
+            ) = (
+                ctx.accounts.prize_vault.as_ref(),
+                ctx.accounts.prize_mint.as_ref(),
+                ctx.accounts.learner_prize_account.as_ref(),
+                ctx.accounts.prize_token_program.as_ref(),
+            )
+            else {
+                return err!(AcademyError::MissingPrizeAccounts);
+            };
+            require_keys_eq!(prize_mint.key(), payout.mint, AcademyError::MintMismatch);
+            require_keys_eq!(
+                learner_prize_account.owner,
+                ctx.accounts.learner.key(),
+                AcademyError::InvalidTokenAccount
+            );
+            let vault_bump = ctx
+                .bumps
+                .prize_vault
+                .ok_or_else(|| error!(AcademyError::MissingPrizeAccounts))?;
+            let reward_item_key = reward_item.key();
+            let vault_seeds: &[&[u8]] = &[b"prize_vault", reward_item_key.as_ref(), &[vault_bump]];
+            transfer_from_vault(
+                prize_vault,
+                prize_mint,
+                learner_prize_account,
+                prize_token_program,
+                vault_seeds,
+                payout.amount,
+            )?;
+            payout.amount
+        }
+        None => 0,
+    };
+
+    let learner = ctx.accounts.learner.key();
+    // The lifetime board ranks XP held, so spent XP comes off it too.
+    ctx.accounts
+        .leaderboard
+        .deduct(learner, reward_item.price_xp, now);
+    let reward_item_key = ctx.accounts.reward_item.key();
+    let price_xp = ctx.accounts.reward_item.price_xp;
+
+    let redemption_tally = &mut ctx.accounts.redemption_tally;
+    let index = redemption_tally.count;
+    if redemption_tally.version == 0 {
+        redemption_tally.reward_item = reward_item_key;
+        redemption_tally.learner = learner;
+        redemption_tally.bump = ctx.bumps.redemption_tally;
+        redemption_tally.version = CURRENT_ACCOUNT_VERSION;
+    }
+    redemption_tally.count = index
+        .checked_add(1)
+        .ok_or_else(|| error!(AcademyError::Overflow))?;
+
+    let reward_item = &mut ctx.accounts.reward_item;
+    reward_item.redeemed_count = reward_item
+        .redeemed_count
+        .checked_add(1)
+        .ok_or_else(|| error!(AcademyError::Overflow))?;
+
+    let redemption = &mut ctx.accounts.redemption;
+    redemption.reward_item = reward_item_key;
+    redemption.learner = learner;
+    redemption.index = index;
+    redemption.price_xp = price_xp;
+    redemption.payout_amount = payout_amount;
+    redemption.redeemed_at = now;
+    redemption.bump = ctx.bumps.redemption;
+    redemption.version = CURRENT_ACCOUNT_VERSION;
+
+    emit!(RewardRedeemed {
+        reward_id: reward_item.reward_id.clone(),
+        learner,
+        redemption: redemption.key(),
+        index,
+        price_xp,
+        payout_amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RedeemReward<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"reward_item", &id_seed(&reward_item.reward_id)[..]],
+        bump = reward_item.bump
+    )]
+    pub reward_item: Account<'info, RewardItem>,
+    #[account(
+        init_if_needed,
+        payer = learner,
+        space = 8 + RedemptionTally::LEN,
+        seeds = [b"redemption_tally", reward_item.key().as_ref(), learner.key().as_ref()],
+        bump
+    )]
+    pub redemption_tally: Account<'info, RedemptionTally>,
+    #[account(
+        init,
+        payer = learner,
+        space = 8 + Redemption::LEN,
+        seeds = [
+            b"redemption",
+            reward_item.key().as_ref(),
+            learner.key().as_ref(),
+            &redemption_tally.count.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub redemption: Account<'info, Redemption>,
+    #[account(mut, address = config.xp_mint @ AcademyError::MintMismatch)]
+    pub xp_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = xp_mint,
+        token::authority = learner,
+        token::token_program = token_program,
+    )]
+    pub learner_xp_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &LIFETIME_LEADERBOARD.to_le_bytes()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    #[account(mut, seeds = [b"prize_vault", reward_item.key().as_ref()], bump)]
+    pub prize_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub prize_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub learner_prize_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub prize_token_program: Option<Interface<'info, TokenInterface>>,
+    #[account(mut)]
+    pub learner: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct RewardRedeemed {
+    pub reward_id: String,
+    pub learner: Pubkey,
+    pub redemption: Pubkey,
+    pub index: u32,
+    pub price_xp: u64,
+    pub payout_amount: u64,
+}
@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    errors::AcademyError,
+    instructions::finalize_course::CommitmentReturned,
+    state::{Cohort, Course, Enrollment, EnrollmentArchive},
+    utils::{
+        id_seed, pay_out_commitment, release_cohort_seat, transfer_from_vault, CommitmentVault,
+    },
+};
+
+pub fn refund_enrollment(ctx: Context<RefundEnrollment>) -> Result<()> {
+    let course = &ctx.accounts.course;
+    let enrollment = &ctx.accounts.enrollment;
+
+    require!(enrollment.course_id == course.course_id, AcademyError::InvalidCourseId);
+    require_keys_eq!(
+        enrollment.learner,
+        ctx.accounts.learner.key(),
+        AcademyError::Unauthorized
+    );
+    require!(enrollment.escrowed_amount > 0, AcademyError::NothingEscrowed);
+    require!(
+        enrollment.completed_at.is_none(),
+        AcademyError::CourseAlreadyFinalized
+    );
+
+    let pricing = course
+        .pricing
+        .ok_or_else(|| error!(AcademyError::CourseNotPriced))?;
+    let now = Clock::get()?.unix_timestamp;
+    let within_window = now.saturating_sub(enrollment.enrolled_at) <= pricing.refund_window_secs;
+    let below_lesson_limit =
+        enrollment.completed_lesson_count() < u32::from(pricing.refund_max_lessons);
+    require!(
+        within_window || below_lesson_limit,
+        AcademyError::RefundNotAvailable
+    );
+
+    require_keys_eq!(
+        ctx.accounts.payment_mint.key(),
+        pricing.mint,
+        AcademyError::MintMismatch
+    );
+    require_keys_eq!(
+        ctx.accounts.learner_payment_account.owner,
+        ctx.accounts.learner.key(),
+        AcademyError::InvalidTokenAccount
+    );
+
+    let amount = enrollment.escrowed_amount;
+    let course_key = course.key();
+    transfer_from_vault(
+        &ctx.accounts.course_escrow,
+        &ctx.accounts.payment_mint,
+        &ctx.accounts.learner_payment_account,
+        &ctx.accounts.token_program,
+        &[b"course_escrow", course_key.as_ref(), &[ctx.bumps.course_escrow]],
+        amount,
+    )?;
+
+    // A refund unwinds the enrollment, so any commitment stake goes back too.
+    if enrollment.commitment_amount > 0 {
+        let commitment = pay_out_commitment(
+            course,
+            enrollment,
+            Some(&enrollment.to_account_info()),
+            &ctx.accounts.learner.to_account_info(),
+            ctx.accounts.learner_commitment_account.as_ref(),
+            CommitmentVault {
+                vault: ctx
+                    .accounts
+                    .commitment_vault
+                    .as_ref()
+                    .zip(ctx.bumps.commitment_vault),
+                mint: ctx.accounts.commitment_mint.as_ref(),
+                token_program: ctx.accounts.commitment_token_program.as_ref(),
+            },
+        )?;
+
+        emit!(CommitmentReturned {
+            learner: ctx.accounts.learner.key(),
+            course_id: course.course_id.clone(),
+            amount: commitment,
+        });
+    }
+
+    release_cohort_seat(enrollment, ctx.accounts.cohort.as_mut())?;
+    ctx.accounts.enrollment_archive.record_attempt(
+        enrollment,
+        ctx.bumps.enrollment_archive,
+        now,
+    )?;
+
+    emit!(EnrollmentRefunded {
+        learner: ctx.accounts.learner.key(),
+        course_id: course.course_id.clone(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RefundEnrollment<'info> {
+    #[account(seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
+    pub course: Account<'info, Course>,
+    #[account(
+        mut,
+        close = learner,
+        seeds = [b"enrollment", &id_seed(&enrollment.course_id)[..], learner.key().as_ref()],
+        bump = enrollment.bump
+    )]
+    pub enrollment: Account<'info, Enrollment>,
+    #[account(
+        init_if_needed,
+        payer = learner,
+        space = 8 + EnrollmentArchive::LEN,
+        seeds = [
+            b"enrollment_archive",
+            &id_seed(&enrollment.course_id)[..],
+            learner.key().as_ref()
+        ],
+        bump
+    )]
+    pub enrollment_archive: Account<'info, EnrollmentArchive>,
+    #[account(mut, seeds = [b"course_escrow", course.key().as_ref()], bump)]
+    pub course_escrow: InterfaceAccount<'info, TokenAccount>,
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub learner_payment_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub learner: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut)]
+    pub learner_commitment_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, seeds = [b"commitment_vault", course.key().as_ref()], bump)]
+    pub commitment_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub commitment_mint: Option<InterfaceAccount<'info, Mint>>,
+    pub commitment_token_program: Option<Interface<'info, TokenInterface>>,
+    #[account(mut)]
+    pub cohort: Option<Account<'info, Cohort>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct EnrollmentRefunded {
+    pub learner: Pubkey,
+    pub course_id: String,
+    pub amount: u64,
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{
+        Config, MentorRole, RegisterMentorParams, CURRENT_ACCOUNT_VERSION, MAX_MENTOR_COURSES,
+        MAX_MENTOR_LABEL_LEN, MAX_MENTOR_REVIEW_XP,
+    },
+};
+
+pub fn register_mentor(ctx: Context<RegisterMentor>, params: RegisterMentorParams) -> Result<()> {
+    require!(
+        params.label.len() <= MAX_MENTOR_LABEL_LEN,
+        AcademyError::InvalidMetadata
+    );
+    require!(
+        !params.courses.is_empty() && params.courses.len() <= MAX_MENTOR_COURSES,
+        AcademyError::InvalidMentorCourses
+    );
+    for (i, course) in params.courses.iter().enumerate() {
+        require!(
+            *course != Pubkey::default() && !params.courses[..i].contains(course),
+            AcademyError::InvalidMentorCourses
+        );
+    }
+    require!(params.window_secs >= 0, AcademyError::InvalidAmount);
+    require!(
+        params.window_secs == 0 || params.max_reviews_per_window > 0,
+        AcademyError::InvalidAmount
+    );
+    require!(
+        params.review_xp <= MAX_MENTOR_REVIEW_XP,
+        AcademyError::InvalidAmount
+    );
+
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        ctx.accounts.config.authority,
+        AcademyError::Unauthorized
+    );
+
+    let mentor_role = &mut ctx.accounts.mentor_role;
+    mentor_role.mentor = params.mentor;
+    mentor_role.label = params.label.clone();
+    mentor_role.courses = params.courses;
+    mentor_role.max_reviews_per_window = params.max_reviews_per_window;
+    mentor_role.window_secs = params.window_secs;
+    mentor_role.window_start = 0;
+    mentor_role.window_reviews = 0;
+    mentor_role.review_xp = params.review_xp;
+    mentor_role.total_reviews = 0;
+    mentor_role.is_active = true;
+    mentor_role.created_at = Clock::get()?.unix_timestamp;
+    mentor_role.bump = ctx.bumps.mentor_role;
+    mentor_role.version = CURRENT_ACCOUNT_VERSION;
+
+    emit!(MentorRegistered {
+        mentor: mentor_role.mentor,
+        label: mentor_role.label.clone(),
+        courses: mentor_role.courses.clone(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: RegisterMentorParams)]
+pub struct RegisterMentor<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + MentorRole::LEN,
+        seeds = [b"mentor", params.mentor.as_ref()],
+        bump
+    )]
+    pub mentor_role: Account<'info, MentorRole>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct MentorRegistered {
+    pub mentor: Pubkey,
+    pub label: String,
+    pub courses: Vec<Pubkey>,
+}
@@ -2,7 +2,9 @@ use anchor_lang::prelude::*;
 
 use crate::{
     errors::AcademyError,
-    state::{Config, MinterRole, RegisterMinterParams, MAX_MINTER_LABEL_LEN},
+    state::{
+        Config, MinterRole, RegisterMinterParams, CURRENT_ACCOUNT_VERSION, MAX_MINTER_LABEL_LEN,
+    },
 };
 
 pub fn register_minter(ctx: Context<RegisterMinter>, params: RegisterMinterParams) -> Result<()> {
@@ -10,6 +12,7 @@ pub fn register_minter(ctx: Context<RegisterMinter>, params: RegisterMinterParam
         params.label.len() <= MAX_MINTER_LABEL_LEN,
         AcademyError::InvalidMetadata
     );
+    require!(params.window_secs >= 0, AcademyError::InvalidAmount);
 
     require_keys_eq!(
         ctx.accounts.authority.key(),
@@ -21,10 +24,15 @@ pub fn register_minter(ctx: Context<RegisterMinter>, params: RegisterMinterParam
     minter_role.minter = params.minter;
     minter_role.label = params.label.clone();
     minter_role.max_xp_per_call = params.max_xp_per_call;
-    minter_role.total_xp_minted = crate::state::I80F48 { value: 0 };
+    minter_role.total_xp_minted = crate::state::I80F48::ZERO;
+    minter_role.max_xp_per_window = params.max_xp_per_window;
+    minter_role.window_secs = params.window_secs;
+    minter_role.window_start = 0;
+    minter_role.window_minted = crate::state::I80F48::ZERO;
     minter_role.is_active = true;
     minter_role.created_at = Clock::get()?.unix_timestamp;
     minter_role.bump = ctx.bumps.minter_role;
+    minter_role.version = CURRENT_ACCOUNT_VERSION;
 
     emit!(MinterRegistered {
         minter: minter_role.minter,
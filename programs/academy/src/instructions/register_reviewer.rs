@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{Config, ReviewerRole, CURRENT_ACCOUNT_VERSION},
+};
+
+pub fn register_reviewer(ctx: Context<RegisterReviewer>, reviewer: Pubkey) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        ctx.accounts.config.authority,
+        AcademyError::Unauthorized
+    );
+
+    let reviewer_role = &mut ctx.accounts.reviewer_role;
+    reviewer_role.reviewer = reviewer;
+    reviewer_role.created_at = Clock::get()?.unix_timestamp;
+    reviewer_role.bump = ctx.bumps.reviewer_role;
+    reviewer_role.version = CURRENT_ACCOUNT_VERSION;
+
+    emit!(ReviewerRegistered { reviewer });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reviewer: Pubkey)]
+pub struct RegisterReviewer<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ReviewerRole::LEN,
+        seeds = [b"reviewer", reviewer.as_ref()],
+        bump
+    )]
+    pub reviewer_role: Account<'info, ReviewerRole>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ReviewerRegistered {
+    pub reviewer: Pubkey,
+}
@@ -0,0 +1,253 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+
+use crate::{
+    errors::AcademyError,
+    instructions::migrate_account::config_authority,
+    state::{AchievementReceipt, AchievementType, Course, Enrollment, Versioned},
+    utils::{create_pda_account, id_seed, move_lamports},
+};
+
+type RehomeFn = fn(&RehomeAccount, Pubkey, &[AccountInfo]) -> Result<()>;
+
+// Courses, enrollments and achievements created before ids were hashed into
+// their seeds live at PDAs derived from the raw id, which the program no
+// longer resolves. This copies one to its hashed-id address and closes the
+// original. Run it before `migrate_account`, so anything keyed by the
+// account's address (a course's creator vault) is created against the new one.
+pub fn rehome_account(ctx: Context<RehomeAccount>) -> Result<()> {
+    let discriminator: [u8; 8] = {
+        let data = ctx.accounts.account.try_borrow_data()?;
+        require!(data.len() >= 8, AcademyError::AccountNotRehomable);
+        data[..8]
+            .try_into()
+            .map_err(|_| error!(AcademyError::AccountNotRehomable))?
+    };
+
+    let authority = config_authority(&ctx.accounts.config)?;
+    let rehome_as: RehomeFn = match discriminator {
+        d if d == Course::DISCRIMINATOR => rehome::<Course>,
+        d if d == Enrollment::DISCRIMINATOR => rehome::<Enrollment>,
+        d if d == AchievementType::DISCRIMINATOR => rehome::<AchievementType>,
+        d if d == AchievementReceipt::DISCRIMINATOR => rehome::<AchievementReceipt>,
+        _ => return err!(AcademyError::AccountNotRehomable),
+    };
+    rehome_as(ctx.accounts, authority, ctx.remaining_accounts)?;
+
+    emit!(AccountRehomed {
+        old_address: ctx.accounts.account.key(),
+        new_address: ctx.accounts.new_account.key(),
+        rehomed_by: ctx.accounts.migrator.key(),
+    });
+
+    Ok(())
+}
+
+// Accounts whose PDA is `[SEED, id, owner?]`; `Versioned::owner` supplies the
+// trailing key where there is one.
+trait IdSeeded: Versioned {
+    const SEED: &'static [u8];
+
+    fn id(&self) -> &str;
+
+    fn bump(&self) -> u8;
+
+    fn set_bump(&mut self, bump: u8);
+
+    // Rewrites stored addresses of other raw-id accounts.
+    fn rehome_references(&mut self, _remaining_accounts: &[AccountInfo]) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl IdSeeded for Course {
+    const SEED: &'static [u8] = b"course";
+
+    fn id(&self) -> &str {
+        &self.course_id
+    }
+
+    fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    fn set_bump(&mut self, bump: u8) {
+        self.bump = bump;
+    }
+
+    // `prerequisite` holds the prerequisite course's raw-id address; it is
+    // passed as the first remaining account, from either address, so its id
+    // can be read.
+    fn rehome_references(&mut self, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        let Some(prerequisite) = self.prerequisite else {
+            return Ok(());
+        };
+        let prereq_info = remaining_accounts
+            .first()
+            .ok_or_else(|| error!(AcademyError::AccountNotRehomable))?;
+        require_keys_eq!(
+            *prereq_info.owner,
+            crate::ID,
+            AcademyError::AccountNotRehomable
+        );
+        let prereq_id = decode::<Course>(prereq_info)?.course_id;
+
+        let (raw_key, _) =
+            Pubkey::find_program_address(&[Self::SEED, prereq_id.as_bytes()], &crate::ID);
+        let (hashed_key, _) =
+            Pubkey::find_program_address(&[Self::SEED, &id_seed(&prereq_id)[..]], &crate::ID);
+        require_keys_eq!(prerequisite, raw_key, AcademyError::AccountNotRehomable);
+        require!(
+            prereq_info.key() == raw_key || prereq_info.key() == hashed_key,
+            AcademyError::AccountNotRehomable
+        );
+
+        self.prerequisite = Some(hashed_key);
+        Ok(())
+    }
+}
+
+impl IdSeeded for Enrollment {
+    const SEED: &'static [u8] = b"enrollment";
+
+    fn id(&self) -> &str {
+        &self.course_id
+    }
+
+    fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    fn set_bump(&mut self, bump: u8) {
+        self.bump = bump;
+    }
+}
+
+impl IdSeeded for AchievementType {
+    const SEED: &'static [u8] = b"achievement";
+
+    fn id(&self) -> &str {
+        &self.achievement_id
+    }
+
+    fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    fn set_bump(&mut self, bump: u8) {
+        self.bump = bump;
+    }
+}
+
+impl IdSeeded for AchievementReceipt {
+    const SEED: &'static [u8] = b"achievement_receipt";
+
+    fn id(&self) -> &str {
+        &self.achievement_id
+    }
+
+    fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    fn set_bump(&mut self, bump: u8) {
+        self.bump = bump;
+    }
+}
+
+// Reads either layout; the version is left alone for `migrate_account`.
+fn decode<T: Versioned>(info: &AccountInfo) -> Result<T> {
+    let data = info.try_borrow_data()?;
+    match T::from_legacy(&data)? {
+        Some(account) => Ok(account),
+        None => T::try_deserialize(&mut &data[..]),
+    }
+}
+
+fn rehome<T: IdSeeded>(
+    accounts: &RehomeAccount,
+    authority: Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    let old_info = accounts.account.to_account_info();
+    let mut account = decode::<T>(&old_info)?;
+
+    let migrator = accounts.migrator.key();
+    let owner = account.owner();
+    require!(
+        migrator == authority || owner == Some(migrator),
+        AcademyError::Unauthorized
+    );
+
+    let id = account.id().to_string();
+    let old_bump = [account.bump()];
+    let mut old_seeds: Vec<&[u8]> = vec![T::SEED, id.as_bytes()];
+    if let Some(owner) = owner.as_ref() {
+        old_seeds.push(owner.as_ref());
+    }
+    old_seeds.push(&old_bump);
+    let old_key = Pubkey::create_program_address(&old_seeds, &crate::ID)
+        .map_err(|_| error!(AcademyError::AccountNotRehomable))?;
+    require_keys_eq!(old_info.key(), old_key, AcademyError::AccountNotRehomable);
+
+    let hashed_id = id_seed(&id);
+    let mut new_seeds: Vec<&[u8]> = vec![T::SEED, &hashed_id];
+    if let Some(owner) = owner.as_ref() {
+        new_seeds.push(owner.as_ref());
+    }
+    let (new_key, new_bump) = Pubkey::find_program_address(&new_seeds, &crate::ID);
+    require_keys_eq!(
+        accounts.new_account.key(),
+        new_key,
+        AcademyError::AccountNotRehomable
+    );
+
+    account.set_bump(new_bump);
+    account.rehome_references(remaining_accounts)?;
+
+    let new_bump = [new_bump];
+    new_seeds.push(&new_bump);
+    let new_info = accounts.new_account.to_account_info();
+    create_pda_account(
+        &new_info,
+        &accounts.migrator.to_account_info(),
+        &accounts.system_program.to_account_info(),
+        T::SPACE,
+        &new_seeds,
+    )?;
+    account.try_serialize(&mut &mut new_info.try_borrow_mut_data()?[..])?;
+
+    move_lamports(
+        &old_info,
+        &accounts.migrator.to_account_info(),
+        old_info.lamports(),
+    )?;
+    old_info.assign(&system_program::ID);
+    old_info.realloc(0, false)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RehomeAccount<'info> {
+    /// CHECK: Config PDA, address-checked; only `authority` is read from it.
+    #[account(seeds = [b"config"], bump)]
+    pub config: UncheckedAccount<'info>,
+    /// CHECK: A course, enrollment, achievement type or receipt at its raw-id
+    /// PDA; its type is resolved from the discriminator.
+    #[account(mut, owner = crate::ID @ AcademyError::AccountNotRehomable)]
+    pub account: UncheckedAccount<'info>,
+    /// CHECK: The account's hashed-id PDA, address-checked before it is created.
+    #[account(mut)]
+    pub new_account: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub migrator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct AccountRehomed {
+    pub old_address: Pubkey,
+    pub new_address: Pubkey,
+    pub rehomed_by: Pubkey,
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{
+        Config, Course, CourseStatus, CourseSubmission, CreatorVault, ReviewerRole, BPS_DENOMINATOR,
+    },
+    utils::{id_seed, move_lamports},
+};
+
+pub fn reject_course(ctx: Context<RejectCourse>, reason_hash: [u8; 32]) -> Result<()> {
+    let course = &ctx.accounts.course;
+    require!(
+        course.status == CourseStatus::Pending,
+        AcademyError::CourseNotPending
+    );
+
+    let deposit = ctx.accounts.submission.deposit;
+    let slashed = u64::try_from(
+        u128::from(deposit) * u128::from(ctx.accounts.config.rejection_slash_bps)
+            / u128::from(BPS_DENOMINATOR),
+    )
+    .map_err(|_| error!(AcademyError::Overflow))?;
+
+    if slashed > 0 {
+        move_lamports(
+            &ctx.accounts.submission.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            slashed,
+        )?;
+    }
+
+    emit!(CourseRejected {
+        course_id: course.course_id.clone(),
+        reviewer: ctx.accounts.reviewer.key(),
+        reason_hash,
+        deposit_slashed: slashed,
+        deposit_returned: deposit - slashed,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RejectCourse<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    // Closed with its vault so the creator can resubmit under the same id.
+    #[account(
+        mut,
+        close = submitter,
+        seeds = [b"course", &id_seed(&course.course_id)[..]],
+        bump = course.bump
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        mut,
+        close = submitter,
+        seeds = [b"creator_vault", course.key().as_ref()],
+        bump = creator_vault.bump
+    )]
+    pub creator_vault: Account<'info, CreatorVault>,
+    #[account(
+        mut,
+        close = submitter,
+        seeds = [b"submission", course.key().as_ref()],
+        bump = submission.bump,
+        constraint = submission.submitter == submitter.key() @ AcademyError::Unauthorized,
+    )]
+    pub submission: Account<'info, CourseSubmission>,
+    /// CHECK: Verified against `submission.submitter`; receives the unslashed deposit and
+    /// the rent of the closed accounts.
+    #[account(mut)]
+    pub submitter: UncheckedAccount<'info>,
+    /// CHECK: Verified against `config.treasury`; receives the slashed share of the deposit.
+    #[account(mut, address = config.treasury @ AcademyError::TreasuryMismatch)]
+    pub treasury: UncheckedAccount<'info>,
+    #[account(seeds = [b"reviewer", reviewer.key().as_ref()], bump = reviewer_role.bump)]
+    pub reviewer_role: Account<'info, ReviewerRole>,
+    pub reviewer: Signer<'info>,
+}
+
+#[event]
+pub struct CourseRejected {
+    pub course_id: String,
+    pub reviewer: Pubkey,
+    pub reason_hash: [u8; 32],
+    pub deposit_slashed: u64,
+    pub deposit_returned: u64,
+}
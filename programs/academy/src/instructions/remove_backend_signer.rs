@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{BackendSignerRole, Config, I80F48},
+};
+
+pub fn remove_backend_signer(ctx: Context<RemoveBackendSigner>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        config.authority,
+        AcademyError::Unauthorized
+    );
+
+    let signer_role = &mut ctx.accounts.backend_signer_role;
+    require!(signer_role.is_active, AcademyError::BackendSignerNotValid);
+    signer_role.is_active = false;
+    config.backend_signer_count = config.backend_signer_count.saturating_sub(1);
+
+    emit!(BackendSignerRemoved {
+        signer: signer_role.signer,
+        total_xp_minted: signer_role.total_xp_minted,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveBackendSigner<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"backend_signer", backend_signer_role.signer.as_ref()],
+        bump = backend_signer_role.bump
+    )]
+    pub backend_signer_role: Account<'info, BackendSignerRole>,
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct BackendSignerRemoved {
+    pub signer: Pubkey,
+    pub total_xp_minted: I80F48,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{Config, Course, CourseRole},
+    utils::id_seed,
+};
+
+pub fn revoke_course_role(ctx: Context<RevokeCourseRole>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        ctx.accounts.config.authority,
+        AcademyError::Unauthorized
+    );
+
+    emit!(CourseRoleRevoked {
+        course_id: ctx.accounts.course.course_id.clone(),
+        holder: ctx.accounts.course_role.holder,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeCourseRole<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
+    pub course: Account<'info, Course>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"course_role", course.key().as_ref(), course_role.holder.as_ref()],
+        bump = course_role.bump
+    )]
+    pub course_role: Account<'info, CourseRole>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct CourseRoleRevoked {
+    pub course_id: String,
+    pub holder: Pubkey,
+}
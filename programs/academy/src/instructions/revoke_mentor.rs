@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{Config, MentorRole},
+};
+
+pub fn revoke_mentor(ctx: Context<RevokeMentor>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        ctx.accounts.config.authority,
+        AcademyError::Unauthorized
+    );
+
+    emit!(MentorRevoked {
+        mentor: ctx.accounts.mentor_role.mentor,
+        total_reviews: ctx.accounts.mentor_role.total_reviews,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeMentor<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"mentor", mentor_role.mentor.as_ref()],
+        bump = mentor_role.bump
+    )]
+    pub mentor_role: Account<'info, MentorRole>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct MentorRevoked {
+    pub mentor: Pubkey,
+    pub total_reviews: u64,
+}
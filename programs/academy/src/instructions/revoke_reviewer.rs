@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::AcademyError, state::{Config, ReviewerRole}};
+
+pub fn revoke_reviewer(ctx: Context<RevokeReviewer>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        ctx.accounts.config.authority,
+        AcademyError::Unauthorized
+    );
+
+    emit!(ReviewerRevoked {
+        reviewer: ctx.accounts.reviewer_role.reviewer,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeReviewer<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"reviewer", reviewer_role.reviewer.as_ref()],
+        bump = reviewer_role.bump
+    )]
+    pub reviewer_role: Account<'info, ReviewerRole>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct ReviewerRevoked {
+    pub reviewer: Pubkey,
+}
@@ -3,17 +3,26 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
     errors::AcademyError,
-    state::{Config, I80F48, MinterRole},
-    utils::{checked_add_i80, i80_to_u32, mint_xp},
+    state::{Config, Guild, I80F48, Leaderboard, MinterRole, LIFETIME_LEADERBOARD},
+    utils::{checked_add_i80, i80_to_u32, mint_xp, record_guild_xp, record_leaderboards},
 };
 
 pub fn reward_xp(ctx: Context<RewardXp>, amount: I80F48, reason: String) -> Result<()> {
     require!(ctx.accounts.minter_role.is_active, AcademyError::MinterNotActive);
+    ctx.accounts.minter_role.require_fixed_point()?;
     require!(amount.value > 0, AcademyError::InvalidAmount);
-
-    let amount_u64 = amount.as_u64()?;
-    let max_u64 = ctx.accounts.minter_role.max_xp_per_call.as_u64()?;
-    require!(amount_u64 <= max_u64, AcademyError::MinterAmountExceeded);
+    require!(
+        amount.value <= ctx.accounts.minter_role.max_xp_per_call.value,
+        AcademyError::MinterAmountExceeded
+    );
+    // Fractional XP is truncated when minted, so the role is charged what is
+    // actually minted; XP has no decimals, so that is whole XP.
+    let minted = amount.to_token_amount(ctx.accounts.xp_mint.decimals)?;
+    require!(minted > 0, AcademyError::InvalidAmount);
+    let charged = I80F48::from_u64(minted);
+    ctx.accounts
+        .minter_role
+        .consume_window(charged, Clock::get()?.unix_timestamp)?;
 
     require_keys_eq!(
         ctx.accounts.xp_mint.key(),
@@ -30,18 +39,35 @@ pub fn reward_xp(ctx: Context<RewardXp>, amount: I80F48, reason: String) -> Resu
         &ctx.accounts.config,
         &ctx.accounts.xp_mint,
         &ctx.accounts.recipient_token_account,
+        ctx.accounts
+            .season_mint
+            .as_ref()
+            .zip(ctx.accounts.recipient_season_account.as_ref()),
         &ctx.accounts.token_program,
-        amount_u64,
+        minted,
+    )?;
+    record_leaderboards(
+        &ctx.accounts.config,
+        &mut ctx.accounts.leaderboard,
+        ctx.accounts.season_leaderboard.as_deref_mut(),
+        &ctx.accounts.recipient_token_account,
+        ctx.accounts.recipient_season_account.as_ref(),
+        minted,
+    )?;
+    record_guild_xp(
+        &ctx.accounts.guild_membership,
+        ctx.accounts.guild.as_deref_mut(),
+        minted,
     )?;
 
     ctx.accounts.minter_role.total_xp_minted = checked_add_i80(
         ctx.accounts.minter_role.total_xp_minted,
-        amount,
+        charged,
     )?;
 
     emit!(XpRewarded {
         recipient: ctx.accounts.recipient_token_account.owner,
-        amount: i80_to_u32(amount)?,
+        amount: i80_to_u32(charged)?,
         reason,
     });
 
@@ -62,6 +88,27 @@ pub struct RewardXp<'info> {
     #[account(mut)]
     pub xp_mint: InterfaceAccount<'info, Mint>,
     #[account(mut)]
+    pub season_mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub recipient_season_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &LIFETIME_LEADERBOARD.to_le_bytes()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &config.current_season.to_le_bytes()],
+        bump = season_leaderboard.bump
+    )]
+    pub season_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+    /// CHECK: The recipient's `GuildMembership` PDA; may not exist.
+    #[account(seeds = [b"guild_membership", recipient_token_account.owner.as_ref()], bump)]
+    pub guild_membership: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub guild: Option<Box<Account<'info, Guild>>>,
+    #[account(mut)]
     pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
     pub minter: Signer<'info>,
     pub token_program: Interface<'info, TokenInterface>,
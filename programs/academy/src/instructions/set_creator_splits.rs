@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{Config, Course, CreatorSplit, CreatorVault, BPS_DENOMINATOR, MAX_CO_CREATORS},
+    utils::id_seed,
+};
+
+pub fn set_creator_splits(ctx: Context<SetCreatorSplits>, splits: Vec<CreatorSplit>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    require!(
+        signer == ctx.accounts.course.creator || signer == ctx.accounts.config.authority,
+        AcademyError::Unauthorized
+    );
+
+    require!(
+        !splits.is_empty() && splits.len() <= MAX_CO_CREATORS,
+        AcademyError::InvalidCreatorSplits
+    );
+    let mut total_bps: u32 = 0;
+    for (i, split) in splits.iter().enumerate() {
+        require!(split.bps > 0, AcademyError::InvalidCreatorSplits);
+        require!(
+            splits[..i].iter().all(|other| other.recipient != split.recipient),
+            AcademyError::InvalidCreatorSplits
+        );
+        total_bps += u32::from(split.bps);
+    }
+    require!(
+        total_bps == u32::from(BPS_DENOMINATOR),
+        AcademyError::InvalidCreatorSplits
+    );
+
+    ctx.accounts.creator_vault.set_splits(&splits)?;
+
+    emit!(CreatorSplitsUpdated {
+        course_id: ctx.accounts.course.course_id.clone(),
+        splits,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCreatorSplits<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
+    pub course: Account<'info, Course>,
+    #[account(
+        mut,
+        seeds = [b"creator_vault", course.key().as_ref()],
+        bump = creator_vault.bump
+    )]
+    pub creator_vault: Account<'info, CreatorVault>,
+    pub signer: Signer<'info>,
+}
+
+#[event]
+pub struct CreatorSplitsUpdated {
+    pub course_id: String,
+    pub splits: Vec<CreatorSplit>,
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::{
+    errors::AcademyError,
+    instructions::create_course::init_course,
+    state::{
+        Config, Course, CourseStatus, CourseSubmission, CreateCourseParams, CreatorVault,
+        CURRENT_ACCOUNT_VERSION,
+    },
+    utils::id_seed,
+};
+
+pub fn submit_course(ctx: Context<SubmitCourse>, params: CreateCourseParams) -> Result<()> {
+    require_keys_eq!(
+        params.creator,
+        ctx.accounts.submitter.key(),
+        AcademyError::Unauthorized
+    );
+
+    let course = &mut ctx.accounts.course;
+    init_course(course, params, CourseStatus::Pending, ctx.bumps.course)?;
+    ctx.accounts.creator_vault.set_inner(CreatorVault::new(
+        course.key(),
+        course.creator,
+        ctx.bumps.creator_vault,
+    ));
+
+    let deposit = ctx.accounts.config.submission_deposit;
+    if deposit > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.submitter.to_account_info(),
+                    to: ctx.accounts.submission.to_account_info(),
+                },
+            ),
+            deposit,
+        )?;
+    }
+
+    let submission = &mut ctx.accounts.submission;
+    submission.course = course.key();
+    submission.submitter = ctx.accounts.submitter.key();
+    submission.deposit = deposit;
+    submission.submitted_at = Clock::get()?.unix_timestamp;
+    submission.bump = ctx.bumps.submission;
+    submission.version = CURRENT_ACCOUNT_VERSION;
+
+    emit!(CourseSubmitted {
+        course_id: course.course_id.clone(),
+        creator: course.creator,
+        deposit,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateCourseParams)]
+pub struct SubmitCourse<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = submitter,
+        space = 8 + Course::LEN,
+        seeds = [b"course", &id_seed(&params.course_id)[..]],
+        bump
+    )]
+    pub course: Account<'info, Course>,
+    #[account(
+        init,
+        payer = submitter,
+        space = 8 + CreatorVault::LEN,
+        seeds = [b"creator_vault", course.key().as_ref()],
+        bump
+    )]
+    pub creator_vault: Account<'info, CreatorVault>,
+    #[account(
+        init,
+        payer = submitter,
+        space = 8 + CourseSubmission::LEN,
+        seeds = [b"submission", course.key().as_ref()],
+        bump
+    )]
+    pub submission: Account<'info, CourseSubmission>,
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct CourseSubmitted {
+    pub course_id: String,
+    pub creator: Pubkey,
+    pub deposit: u64,
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{BackendSignerRole, BackendSignerUpdate, Config},
+};
+
+pub fn update_backend_signer(
+    ctx: Context<UpdateBackendSigner>,
+    changes: BackendSignerUpdate,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        ctx.accounts.config.authority,
+        AcademyError::Unauthorized
+    );
+
+    let signer_role = &mut ctx.accounts.backend_signer_role;
+    if let Some(valid_from) = changes.new_valid_from {
+        signer_role.valid_from = valid_from;
+    }
+    if let Some(valid_until) = changes.new_valid_until {
+        signer_role.valid_until = valid_until;
+    }
+    require!(
+        signer_role.valid_from < signer_role.valid_until,
+        AcademyError::InvalidValidityWindow
+    );
+
+    emit!(BackendSignerUpdated {
+        signer: signer_role.signer,
+        valid_from: signer_role.valid_from,
+        valid_until: signer_role.valid_until,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateBackendSigner<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"backend_signer", backend_signer_role.signer.as_ref()],
+        bump = backend_signer_role.bump
+    )]
+    pub backend_signer_role: Account<'info, BackendSignerRole>,
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct BackendSignerUpdated {
+    pub signer: Pubkey,
+    pub valid_from: i64,
+    pub valid_until: i64,
+}
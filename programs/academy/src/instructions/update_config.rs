@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::AcademyError, state::{Config, ConfigUpdate}};
+use crate::{errors::AcademyError, state::{Config, ConfigUpdate, BPS_DENOMINATOR}};
 
 pub fn update_config(ctx: Context<UpdateConfig>, changes: ConfigUpdate) -> Result<()> {
     let config = &mut ctx.accounts.config;
@@ -11,8 +11,21 @@ pub fn update_config(ctx: Context<UpdateConfig>, changes: ConfigUpdate) -> Resul
         AcademyError::Unauthorized
     );
 
-    if let Some(new_backend_signer) = changes.new_backend_signer {
-        config.backend_signer = new_backend_signer;
+    if let Some(new_treasury) = changes.new_treasury {
+        config.treasury = new_treasury;
+    }
+    if let Some(new_submission_deposit) = changes.new_submission_deposit {
+        config.submission_deposit = new_submission_deposit;
+    }
+    if let Some(new_rejection_slash_bps) = changes.new_rejection_slash_bps {
+        require!(
+            new_rejection_slash_bps <= BPS_DENOMINATOR,
+            AcademyError::InvalidBasisPoints
+        );
+        config.rejection_slash_bps = new_rejection_slash_bps;
+    }
+    if let Some(new_scholarship_pool) = changes.new_scholarship_pool {
+        config.scholarship_pool = new_scholarship_pool;
     }
 
     emit!(crate::instructions::initialize::ConfigUpdated {
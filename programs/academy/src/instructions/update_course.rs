@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::AcademyError, state::{Config, Course, CourseUpdate}};
+use crate::{
+    errors::AcademyError,
+    state::{Config, Course, CourseStatus, CourseUpdate, MAX_LESSONS},
+    utils::id_seed,
+};
 
 pub fn update_course(ctx: Context<UpdateCourse>, changes: CourseUpdate) -> Result<()> {
     require_keys_eq!(
@@ -14,6 +18,10 @@ pub fn update_course(ctx: Context<UpdateCourse>, changes: CourseUpdate) -> Resul
         course.content_tx_id = content_tx_id;
     }
     if let Some(is_active) = changes.new_is_active {
+        require!(
+            !is_active || course.status == CourseStatus::Approved,
+            AcademyError::CourseNotApproved
+        );
         course.is_active = is_active;
     }
     if let Some(xp_per_lesson) = changes.new_xp_per_lesson {
@@ -25,6 +33,24 @@ pub fn update_course(ctx: Context<UpdateCourse>, changes: CourseUpdate) -> Resul
     if let Some(min_completions_for_reward) = changes.new_min_completions_for_reward {
         course.min_completions_for_reward = min_completions_for_reward;
     }
+    if let Some(price_amount) = changes.new_price_amount {
+        let pricing = course
+            .pricing
+            .as_mut()
+            .ok_or_else(|| error!(AcademyError::CourseNotPriced))?;
+        require!(price_amount > 0, AcademyError::InvalidAmount);
+        pricing.amount = price_amount;
+    }
+    if let Some(allow_progress_restore) = changes.new_allow_progress_restore {
+        course.allow_progress_restore = allow_progress_restore;
+    }
+    if let Some(mentor_graded_lessons) = changes.new_mentor_graded_lessons {
+        course.mentor_graded_lessons = mentor_graded_lessons;
+        require!(
+            !(course.lesson_count..=MAX_LESSONS).any(|i| course.is_mentor_graded(i)),
+            AcademyError::LessonOutOfBounds
+        );
+    }
 
     emit!(CourseUpdated {
         course_id: course.course_id.clone(),
@@ -37,7 +63,7 @@ pub fn update_course(ctx: Context<UpdateCourse>, changes: CourseUpdate) -> Resul
 pub struct UpdateCourse<'info> {
     #[account(seeds = [b"config"], bump = config.bump)]
     pub config: Account<'info, Config>,
-    #[account(mut, seeds = [b"course", course.course_id.as_bytes()], bump = course.bump)]
+    #[account(mut, seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
     pub course: Account<'info, Course>,
     pub authority: Signer<'info>,
 }
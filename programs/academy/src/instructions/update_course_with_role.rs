@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::AcademyError,
+    state::{
+        Course, CourseRole, CourseStatus, CourseUpdate, COURSE_ROLE_ADJUST_REWARDS,
+        COURSE_ROLE_TOGGLE_ACTIVE, COURSE_ROLE_UPDATE_CONTENT,
+    },
+    utils::id_seed,
+};
+
+pub fn update_course_with_role(
+    ctx: Context<UpdateCourseWithRole>,
+    changes: CourseUpdate,
+) -> Result<()> {
+    let course_role = &ctx.accounts.course_role;
+    let course = &mut ctx.accounts.course;
+
+    if let Some(content_tx_id) = changes.new_content_tx_id {
+        require!(
+            course_role.has_permission(COURSE_ROLE_UPDATE_CONTENT),
+            AcademyError::CourseRolePermissionDenied
+        );
+        course.content_tx_id = content_tx_id;
+    }
+    if let Some(is_active) = changes.new_is_active {
+        require!(
+            course_role.has_permission(COURSE_ROLE_TOGGLE_ACTIVE),
+            AcademyError::CourseRolePermissionDenied
+        );
+        require!(
+            !is_active || course.status == CourseStatus::Approved,
+            AcademyError::CourseNotApproved
+        );
+        course.is_active = is_active;
+    }
+
+    // Lowering `min_completions_for_reward` would release creator rewards
+    // early, so like pricing it stays with the authority.
+    require!(
+        changes.new_min_completions_for_reward.is_none()
+            && changes.new_price_amount.is_none()
+            && changes.new_allow_progress_restore.is_none()
+            && changes.new_mentor_graded_lessons.is_none(),
+        AcademyError::CourseRolePermissionDenied
+    );
+
+    let touches_rewards =
+        changes.new_xp_per_lesson.is_some() || changes.new_creator_reward_xp.is_some();
+    if touches_rewards {
+        require!(
+            course_role.has_permission(COURSE_ROLE_ADJUST_REWARDS),
+            AcademyError::CourseRolePermissionDenied
+        );
+    }
+    if let Some(xp_per_lesson) = changes.new_xp_per_lesson {
+        require!(
+            xp_per_lesson <= course_role.max_xp_per_lesson,
+            AcademyError::CourseRoleBoundExceeded
+        );
+        course.xp_per_lesson = xp_per_lesson;
+    }
+    if let Some(creator_reward_xp) = changes.new_creator_reward_xp {
+        require!(
+            creator_reward_xp <= course_role.max_creator_reward_xp,
+            AcademyError::CourseRoleBoundExceeded
+        );
+        course.creator_reward_xp = creator_reward_xp;
+    }
+
+    emit!(crate::instructions::update_course::CourseUpdated {
+        course_id: course.course_id.clone(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateCourseWithRole<'info> {
+    #[account(mut, seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
+    pub course: Account<'info, Course>,
+    #[account(
+        seeds = [b"course_role", course.key().as_ref(), holder.key().as_ref()],
+        bump = course_role.bump,
+        constraint = course_role.course == course.key() @ AcademyError::Unauthorized,
+        constraint = course_role.holder == holder.key() @ AcademyError::Unauthorized,
+    )]
+    pub course_role: Account<'info, CourseRole>,
+    pub holder: Signer<'info>,
+}
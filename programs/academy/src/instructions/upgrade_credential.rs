@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::AcademyError, state::{Config, Course, Enrollment, I80F48}};
+use crate::{
+    errors::AcademyError,
+    state::{BackendSignerRole, CompletionRecord, Config, Course, Enrollment, I80F48},
+    utils::id_seed,
+};
 
 pub fn upgrade_credential(
     ctx: Context<UpgradeCredential>,
@@ -9,30 +13,34 @@ pub fn upgrade_credential(
     _courses_completed: u32,
     _total_xp: I80F48,
 ) -> Result<()> {
-    require_keys_eq!(
-        ctx.accounts.backend_signer.key(),
-        ctx.accounts.config.backend_signer,
-        AcademyError::BackendSignerMismatch
-    );
-
-    let enrollment = &ctx.accounts.enrollment;
     require!(
-        enrollment.course_id == ctx.accounts.course.course_id,
-        AcademyError::InvalidCourseId
-    );
-    require_keys_eq!(
-        enrollment.learner,
-        ctx.accounts.learner.key(),
-        AcademyError::Unauthorized
-    );
-    require!(
-        enrollment.completed_at.is_some(),
-        AcademyError::CourseNotFinalized
+        ctx.accounts
+            .backend_signer_role
+            .is_valid_at(Clock::get()?.unix_timestamp),
+        AcademyError::BackendSignerNotValid
     );
 
-    let issued_asset = enrollment
-        .credential_asset
-        .ok_or_else(|| error!(AcademyError::InvalidCredentialAsset))?;
+    let issued_asset = if let Some(enrollment) = ctx.accounts.enrollment.as_ref() {
+        require!(
+            enrollment.course_id == ctx.accounts.course.course_id,
+            AcademyError::InvalidCourseId
+        );
+        require_keys_eq!(
+            enrollment.learner,
+            ctx.accounts.learner.key(),
+            AcademyError::Unauthorized
+        );
+        require!(
+            enrollment.completed_at.is_some(),
+            AcademyError::CourseNotFinalized
+        );
+        enrollment.credential_asset
+    } else if let Some(completion_record) = ctx.accounts.completion_record.as_ref() {
+        completion_record.credential
+    } else {
+        return err!(AcademyError::MissingCompletionProof);
+    }
+    .ok_or_else(|| error!(AcademyError::InvalidCredentialAsset))?;
     require_keys_eq!(
         issued_asset,
         ctx.accounts.credential_asset.key(),
@@ -53,22 +61,32 @@ pub fn upgrade_credential(
 pub struct UpgradeCredential<'info> {
     #[account(seeds = [b"config"], bump = config.bump)]
     pub config: Account<'info, Config>,
-    #[account(seeds = [b"course", course.course_id.as_bytes()], bump = course.bump)]
+    #[account(seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
     pub course: Account<'info, Course>,
     #[account(
-        seeds = [b"enrollment", enrollment.course_id.as_bytes(), learner.key().as_ref()],
+        seeds = [b"enrollment", &id_seed(&course.course_id)[..], learner.key().as_ref()],
         bump = enrollment.bump
     )]
-    pub enrollment: Account<'info, Enrollment>,
-    /// CHECK: Learner pubkey used for ownership checks and enrollment seed validation.
+    pub enrollment: Option<Account<'info, Enrollment>>,
+    #[account(
+        seeds = [b"completion", course.key().as_ref(), learner.key().as_ref()],
+        bump = completion_record.bump
+    )]
+    pub completion_record: Option<Account<'info, CompletionRecord>>,
+    /// CHECK: Learner pubkey used for ownership checks and enrollment/record seed validation.
     pub learner: UncheckedAccount<'info>,
-    /// CHECK: Existing credential asset, verified against enrollment record.
+    /// CHECK: Existing credential asset, verified against the enrollment or completion record.
     #[account(mut)]
     pub credential_asset: UncheckedAccount<'info>,
     /// CHECK: Metaplex Core collection account passed through to integration CPI.
     pub track_collection: UncheckedAccount<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"backend_signer", backend_signer.key().as_ref()],
+        bump = backend_signer_role.bump
+    )]
+    pub backend_signer_role: Account<'info, BackendSignerRole>,
     pub backend_signer: Signer<'info>,
     /// CHECK: Metaplex Core program account passed through to integration CPI.
     pub mpl_core_program: UncheckedAccount<'info>,
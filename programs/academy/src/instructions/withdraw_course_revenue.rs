@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    errors::AcademyError,
+    state::{Config, Course},
+    utils::{id_seed, transfer_from_vault},
+};
+
+pub fn withdraw_course_revenue(ctx: Context<WithdrawCourseRevenue>) -> Result<()> {
+    let course = &mut ctx.accounts.course;
+    let pricing = course
+        .pricing
+        .ok_or_else(|| error!(AcademyError::CourseNotPriced))?;
+
+    require_keys_eq!(
+        ctx.accounts.payment_mint.key(),
+        pricing.mint,
+        AcademyError::MintMismatch
+    );
+    require_keys_eq!(
+        ctx.accounts.creator_token_account.owner,
+        course.creator,
+        AcademyError::InvalidTokenAccount
+    );
+    require_keys_eq!(
+        ctx.accounts.treasury_token_account.owner,
+        ctx.accounts.config.treasury,
+        AcademyError::TreasuryMismatch
+    );
+
+    let creator_amount = course.pending_creator_revenue;
+    let treasury_amount = course.pending_treasury_revenue;
+    require!(
+        creator_amount > 0 || treasury_amount > 0,
+        AcademyError::NothingToClaim
+    );
+    course.pending_creator_revenue = 0;
+    course.pending_treasury_revenue = 0;
+
+    let course_key = course.key();
+    let escrow_bump = [ctx.bumps.course_escrow];
+    let escrow_seeds: &[&[u8]] = &[b"course_escrow", course_key.as_ref(), &escrow_bump];
+    if creator_amount > 0 {
+        transfer_from_vault(
+            &ctx.accounts.course_escrow,
+            &ctx.accounts.payment_mint,
+            &ctx.accounts.creator_token_account,
+            &ctx.accounts.token_program,
+            escrow_seeds,
+            creator_amount,
+        )?;
+    }
+    if treasury_amount > 0 {
+        transfer_from_vault(
+            &ctx.accounts.course_escrow,
+            &ctx.accounts.payment_mint,
+            &ctx.accounts.treasury_token_account,
+            &ctx.accounts.token_program,
+            escrow_seeds,
+            treasury_amount,
+        )?;
+    }
+
+    emit!(CourseRevenueWithdrawn {
+        course_id: course.course_id.clone(),
+        creator_amount,
+        treasury_amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawCourseRevenue<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"course", &id_seed(&course.course_id)[..]], bump = course.bump)]
+    pub course: Account<'info, Course>,
+    #[account(mut, seeds = [b"course_escrow", course.key().as_ref()], bump)]
+    pub course_escrow: InterfaceAccount<'info, TokenAccount>,
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(mut)]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[event]
+pub struct CourseRevenueWithdrawn {
+    pub course_id: String,
+    pub creator_amount: u64,
+    pub treasury_amount: u64,
+}
@@ -9,59 +9,417 @@ use instructions::*;
 
 declare_id!("2JEFfbRwBqZB3nf5JkTGsievs43CDuGettfzBWzf94Mw");
 
-#[program]
-pub mod academy {
-    use super::*;
+#[cfg(feature = "light")]
+pub const LIGHT_CPI_SIGNER: light_sdk::cpi::CpiSigner =
+    light_sdk::derive_light_cpi_signer!("2JEFfbRwBqZB3nf5JkTGsievs43CDuGettfzBWzf94Mw");
 
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-        instructions::initialize::initialize(ctx)
-    }
+// `#[program]` does not honour `#[cfg]` on individual handlers, so the
+// handlers that depend on how enrollments and achievement receipts are stored
+// are passed in here and spliced into the module.
+macro_rules! academy_program {
+    ($($storage_handlers:tt)*) => {
+        #[program]
+        pub mod academy {
+            use super::*;
 
-    pub fn update_config(
-        ctx: Context<UpdateConfig>,
-        changes: state::ConfigUpdate,
-    ) -> Result<()> {
-        instructions::update_config::update_config(ctx, changes)
-    }
+            $($storage_handlers)*
 
-    pub fn create_course(
-        ctx: Context<CreateCourse>,
-        params: state::CreateCourseParams,
-    ) -> Result<()> {
-        instructions::create_course::create_course(ctx, params)
-    }
+            pub fn initialize(
+                ctx: Context<Initialize>,
+                params: state::InitializeParams,
+            ) -> Result<()> {
+                instructions::initialize::initialize(ctx, params)
+            }
 
-    pub fn update_course(
-        ctx: Context<UpdateCourse>,
-        changes: state::CourseUpdate,
-    ) -> Result<()> {
-        instructions::update_course::update_course(ctx, changes)
-    }
+            pub fn update_config(
+                ctx: Context<UpdateConfig>,
+                changes: state::ConfigUpdate,
+            ) -> Result<()> {
+                instructions::update_config::update_config(ctx, changes)
+            }
 
-    pub fn register_minter(
-        ctx: Context<RegisterMinter>,
-        params: state::RegisterMinterParams,
-    ) -> Result<()> {
-        instructions::register_minter::register_minter(ctx, params)
-    }
+            pub fn create_course(
+                ctx: Context<CreateCourse>,
+                params: state::CreateCourseParams,
+            ) -> Result<()> {
+                instructions::create_course::create_course(ctx, params)
+            }
 
-    pub fn revoke_minter(ctx: Context<RevokeMinter>) -> Result<()> {
-        instructions::revoke_minter::revoke_minter(ctx)
-    }
+            pub fn update_course(
+                ctx: Context<UpdateCourse>,
+                changes: state::CourseUpdate,
+            ) -> Result<()> {
+                instructions::update_course::update_course(ctx, changes)
+            }
 
-    pub fn create_achievement_type(
-        ctx: Context<CreateAchievementType>,
-        params: state::CreateAchievementTypeParams,
-    ) -> Result<()> {
-        instructions::create_achievement_type::create_achievement_type(ctx, params)
-    }
+            pub fn register_minter(
+                ctx: Context<RegisterMinter>,
+                params: state::RegisterMinterParams,
+            ) -> Result<()> {
+                instructions::register_minter::register_minter(ctx, params)
+            }
 
-    pub fn deactivate_achievement_type(
-        ctx: Context<DeactivateAchievementType>,
-    ) -> Result<()> {
-        instructions::deactivate_achievement_type::deactivate_achievement_type(ctx)
-    }
+            pub fn revoke_minter(ctx: Context<RevokeMinter>) -> Result<()> {
+                instructions::revoke_minter::revoke_minter(ctx)
+            }
+
+            pub fn create_achievement_type(
+                ctx: Context<CreateAchievementType>,
+                params: state::CreateAchievementTypeParams,
+            ) -> Result<()> {
+                instructions::create_achievement_type::create_achievement_type(ctx, params)
+            }
+
+            pub fn deactivate_achievement_type(
+                ctx: Context<DeactivateAchievementType>,
+            ) -> Result<()> {
+                instructions::deactivate_achievement_type::deactivate_achievement_type(ctx)
+            }
+
+            pub fn close_enrollment(
+                ctx: Context<CloseEnrollment>,
+            ) -> Result<()> {
+                instructions::close_enrollment::close_enrollment(ctx)
+            }
+
+            pub fn issue_credential(
+                ctx: Context<IssueCredential>,
+                credential_name: String,
+                metadata_uri: String,
+                courses_completed: u32,
+                total_xp: state::I80F48,
+            ) -> Result<()> {
+                instructions::issue_credential::issue_credential(
+                    ctx,
+                    credential_name,
+                    metadata_uri,
+                    courses_completed,
+                    total_xp,
+                )
+            }
+
+            pub fn upgrade_credential(
+                ctx: Context<UpgradeCredential>,
+                new_name: String,
+                new_uri: String,
+                courses_completed: u32,
+                total_xp: state::I80F48,
+            ) -> Result<()> {
+                instructions::upgrade_credential::upgrade_credential(
+                    ctx,
+                    new_name,
+                    new_uri,
+                    courses_completed,
+                    total_xp,
+                )
+            }
+
+            pub fn reward_xp(
+                ctx: Context<RewardXp>,
+                amount: state::I80F48,
+                reason: String,
+            ) -> Result<()> {
+                instructions::reward_xp::reward_xp(ctx, amount, reason)
+            }
+
+            pub fn grant_course_role(
+                ctx: Context<GrantCourseRole>,
+                params: state::GrantCourseRoleParams,
+            ) -> Result<()> {
+                instructions::grant_course_role::grant_course_role(ctx, params)
+            }
+
+            pub fn revoke_course_role(ctx: Context<RevokeCourseRole>) -> Result<()> {
+                instructions::revoke_course_role::revoke_course_role(ctx)
+            }
+
+            pub fn update_course_with_role(
+                ctx: Context<UpdateCourseWithRole>,
+                changes: state::CourseUpdate,
+            ) -> Result<()> {
+                instructions::update_course_with_role::update_course_with_role(ctx, changes)
+            }
+
+            pub fn register_reviewer(
+                ctx: Context<RegisterReviewer>,
+                reviewer: Pubkey,
+            ) -> Result<()> {
+                instructions::register_reviewer::register_reviewer(ctx, reviewer)
+            }
+
+            pub fn revoke_reviewer(ctx: Context<RevokeReviewer>) -> Result<()> {
+                instructions::revoke_reviewer::revoke_reviewer(ctx)
+            }
+
+            pub fn submit_course(
+                ctx: Context<SubmitCourse>,
+                params: state::CreateCourseParams,
+            ) -> Result<()> {
+                instructions::submit_course::submit_course(ctx, params)
+            }
+
+            pub fn approve_course(
+                ctx: Context<ApproveCourse>,
+                reason_hash: [u8; 32],
+            ) -> Result<()> {
+                instructions::approve_course::approve_course(ctx, reason_hash)
+            }
+
+            pub fn reject_course(ctx: Context<RejectCourse>, reason_hash: [u8; 32]) -> Result<()> {
+                instructions::reject_course::reject_course(ctx, reason_hash)
+            }
+
+            pub fn claim_creator_reward(ctx: Context<ClaimCreatorReward>) -> Result<()> {
+                instructions::claim_creator_reward::claim_creator_reward(ctx)
+            }
+
+            pub fn set_creator_splits(
+                ctx: Context<SetCreatorSplits>,
+                splits: Vec<state::CreatorSplit>,
+            ) -> Result<()> {
+                instructions::set_creator_splits::set_creator_splits(ctx, splits)
+            }
+
+            pub fn complete_lesson_attested(
+                ctx: Context<CompleteLessonAttested>,
+                lesson_index: u8,
+                nonce: u64,
+                expiry: i64,
+            ) -> Result<()> {
+                instructions::complete_lesson_attested::complete_lesson_attested(
+                    ctx,
+                    lesson_index,
+                    nonce,
+                    expiry,
+                )
+            }
+
+            pub fn finalize_course_attested(
+                ctx: Context<FinalizeCourseAttested>,
+                nonce: u64,
+                expiry: i64,
+            ) -> Result<()> {
+                instructions::finalize_course_attested::finalize_course_attested(ctx, nonce, expiry)
+            }
+
+            pub fn add_backend_signer(
+                ctx: Context<AddBackendSigner>,
+                params: state::AddBackendSignerParams,
+            ) -> Result<()> {
+                instructions::add_backend_signer::add_backend_signer(ctx, params)
+            }
+
+            pub fn update_backend_signer(
+                ctx: Context<UpdateBackendSigner>,
+                changes: state::BackendSignerUpdate,
+            ) -> Result<()> {
+                instructions::update_backend_signer::update_backend_signer(ctx, changes)
+            }
+
+            pub fn remove_backend_signer(ctx: Context<RemoveBackendSigner>) -> Result<()> {
+                instructions::remove_backend_signer::remove_backend_signer(ctx)
+            }
+
+            pub fn complete_lessons(
+                ctx: Context<CompleteLessons>,
+                lesson_mask: [u64; 4],
+            ) -> Result<()> {
+                instructions::complete_lessons::complete_lessons(ctx, lesson_mask)
+            }
+
+            pub fn complete_lessons_attested(
+                ctx: Context<CompleteLessonsAttested>,
+                lesson_mask: [u64; 4],
+                nonce: u64,
+                expiry: i64,
+            ) -> Result<()> {
+                instructions::complete_lessons_attested::complete_lessons_attested(
+                    ctx,
+                    lesson_mask,
+                    nonce,
+                    expiry,
+                )
+            }
+
+            pub fn batch_reward_xp<'info>(
+                ctx: Context<'_, '_, 'info, 'info, BatchRewardXp<'info>>,
+                amounts: Vec<state::I80F48>,
+                reason: String,
+            ) -> Result<()> {
+                instructions::batch_reward_xp::batch_reward_xp(ctx, amounts, reason)
+            }
+
+            pub fn init_course_escrow(ctx: Context<InitCourseEscrow>) -> Result<()> {
+                instructions::init_course_escrow::init_course_escrow(ctx)
+            }
+
+            pub fn refund_enrollment(ctx: Context<RefundEnrollment>) -> Result<()> {
+                instructions::refund_enrollment::refund_enrollment(ctx)
+            }
+
+            pub fn withdraw_course_revenue(ctx: Context<WithdrawCourseRevenue>) -> Result<()> {
+                instructions::withdraw_course_revenue::withdraw_course_revenue(ctx)
+            }
+
+            pub fn init_commitment_vault(ctx: Context<InitCommitmentVault>) -> Result<()> {
+                instructions::init_commitment_vault::init_commitment_vault(ctx)
+            }
+
+            pub fn forfeit_commitment(ctx: Context<ForfeitCommitment>) -> Result<()> {
+                instructions::forfeit_commitment::forfeit_commitment(ctx)
+            }
+
+            pub fn expire_enrollment(ctx: Context<ExpireEnrollment>) -> Result<()> {
+                instructions::expire_enrollment::expire_enrollment(ctx)
+            }
+
+            pub fn create_cohort(
+                ctx: Context<CreateCohort>,
+                params: state::CreateCohortParams,
+            ) -> Result<()> {
+                instructions::create_cohort::create_cohort(ctx, params)
+            }
+
+            pub fn enroll_in_cohort(
+                ctx: Context<EnrollInCohort>,
+                course_id: String,
+                cohort_id: u32,
+            ) -> Result<()> {
+                instructions::enroll_in_cohort::enroll_in_cohort(ctx, course_id, cohort_id)
+            }
 
+            pub fn join_cohort_waitlist(ctx: Context<JoinCohortWaitlist>) -> Result<()> {
+                instructions::join_cohort_waitlist::join_cohort_waitlist(ctx)
+            }
+
+            pub fn leave_cohort_waitlist(ctx: Context<LeaveCohortWaitlist>) -> Result<()> {
+                instructions::leave_cohort_waitlist::leave_cohort_waitlist(ctx)
+            }
+
+            pub fn compact_enrollment(ctx: Context<CompactEnrollment>) -> Result<()> {
+                instructions::compact_enrollment::compact_enrollment(ctx)
+            }
+
+            pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+                instructions::migrate_account::migrate_account(ctx)
+            }
+
+            pub fn freeze_learner_xp(ctx: Context<FreezeLearnerXp>) -> Result<()> {
+                instructions::freeze_learner_xp::freeze_learner_xp(ctx)
+            }
+
+            pub fn thaw_learner_xp(ctx: Context<ThawLearnerXp>) -> Result<()> {
+                instructions::thaw_learner_xp::thaw_learner_xp(ctx)
+            }
+
+            pub fn clawback_xp(
+                ctx: Context<ClawbackXp>,
+                amount: u64,
+                reason_code: u8,
+            ) -> Result<()> {
+                instructions::clawback_xp::clawback_xp(ctx, amount, reason_code)
+            }
+
+            pub fn create_reward_item(
+                ctx: Context<CreateRewardItem>,
+                params: state::CreateRewardItemParams,
+            ) -> Result<()> {
+                instructions::create_reward_item::create_reward_item(ctx, params)
+            }
+
+            pub fn deactivate_reward_item(ctx: Context<DeactivateRewardItem>) -> Result<()> {
+                instructions::deactivate_reward_item::deactivate_reward_item(ctx)
+            }
+
+            pub fn init_prize_vault(ctx: Context<InitPrizeVault>) -> Result<()> {
+                instructions::init_prize_vault::init_prize_vault(ctx)
+            }
+
+            pub fn redeem_reward(ctx: Context<RedeemReward>) -> Result<()> {
+                instructions::redeem_reward::redeem_reward(ctx)
+            }
+
+            pub fn create_season(
+                ctx: Context<CreateSeason>,
+                params: state::CreateSeasonParams,
+            ) -> Result<()> {
+                instructions::create_season::create_season(ctx, params)
+            }
+
+            pub fn close_season(ctx: Context<CloseSeason>) -> Result<()> {
+                instructions::close_season::close_season(ctx)
+            }
+
+            pub fn init_leaderboard(ctx: Context<InitLeaderboard>) -> Result<()> {
+                instructions::init_leaderboard::init_leaderboard(ctx)
+            }
+
+            pub fn create_distribution(
+                ctx: Context<CreateDistribution>,
+                params: state::CreateDistributionParams,
+            ) -> Result<()> {
+                instructions::create_distribution::create_distribution(ctx, params)
+            }
+
+            pub fn claim_distribution(
+                ctx: Context<ClaimDistribution>,
+                index: u32,
+                amount: u64,
+                proof: Vec<[u8; 32]>,
+            ) -> Result<()> {
+                instructions::claim_distribution::claim_distribution(ctx, index, amount, proof)
+            }
+
+            pub fn clawback_distribution(ctx: Context<ClawbackDistribution>) -> Result<()> {
+                instructions::clawback_distribution::clawback_distribution(ctx)
+            }
+
+            pub fn create_guild(
+                ctx: Context<CreateGuild>,
+                params: state::CreateGuildParams,
+            ) -> Result<()> {
+                instructions::create_guild::create_guild(ctx, params)
+            }
+
+            pub fn join_guild(ctx: Context<JoinGuild>) -> Result<()> {
+                instructions::join_guild::join_guild(ctx)
+            }
+
+            pub fn leave_guild(ctx: Context<LeaveGuild>) -> Result<()> {
+                instructions::leave_guild::leave_guild(ctx)
+            }
+
+            pub fn register_mentor(
+                ctx: Context<RegisterMentor>,
+                params: state::RegisterMentorParams,
+            ) -> Result<()> {
+                instructions::register_mentor::register_mentor(ctx, params)
+            }
+
+            pub fn revoke_mentor(ctx: Context<RevokeMentor>) -> Result<()> {
+                instructions::revoke_mentor::revoke_mentor(ctx)
+            }
+
+            pub fn complete_lesson_mentor(
+                ctx: Context<CompleteLessonMentor>,
+                lesson_index: u8,
+            ) -> Result<()> {
+                instructions::complete_lesson_mentor::complete_lesson_mentor(ctx, lesson_index)
+            }
+
+            pub fn init_creator_vault(ctx: Context<InitCreatorVault>) -> Result<()> {
+                instructions::init_creator_vault::init_creator_vault(ctx)
+            }
+
+            pub fn rehome_account(ctx: Context<RehomeAccount>) -> Result<()> {
+                instructions::rehome_account::rehome_account(ctx)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "light"))]
+academy_program! {
     pub fn enroll(
         ctx: Context<Enroll>,
         course_id: String,
@@ -69,12 +427,6 @@ pub mod academy {
         instructions::enroll::enroll(ctx, course_id)
     }
 
-    pub fn close_enrollment(
-        ctx: Context<CloseEnrollment>,
-    ) -> Result<()> {
-        instructions::close_enrollment::close_enrollment(ctx)
-    }
-
     pub fn complete_lesson(
         ctx: Context<CompleteLesson>,
         lesson_index: u8,
@@ -88,49 +440,74 @@ pub mod academy {
         instructions::finalize_course::finalize_course(ctx)
     }
 
-    pub fn issue_credential(
-        ctx: Context<IssueCredential>,
-        credential_name: String,
-        metadata_uri: String,
-        courses_completed: u32,
-        total_xp: state::I80F48,
+    pub fn award_achievement(
+        ctx: Context<AwardAchievement>,
+    ) -> Result<()> {
+        instructions::award_achievement::award_achievement(ctx)
+    }
+}
+
+// With `light`, enrollments and achievement receipts live in Light Protocol
+// state trees instead of PDAs, and these handlers take the validity proof
+// and tree positions for them. The light system program accounts follow the
+// named accounts in `remaining_accounts`.
+#[cfg(feature = "light")]
+academy_program! {
+    pub fn enroll<'info>(
+        ctx: Context<'_, '_, '_, 'info, EnrollCompressed<'info>>,
+        course_id: String,
+        proof: light_sdk::instruction::ValidityProof,
+        address_tree_info: light_sdk::instruction::PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        prerequisite: Option<state::CompressedEnrollment>,
     ) -> Result<()> {
-        instructions::issue_credential::issue_credential(
+        instructions::enroll_compressed::enroll_compressed(
             ctx,
-            credential_name,
-            metadata_uri,
-            courses_completed,
-            total_xp,
+            course_id,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            prerequisite,
         )
     }
 
-    pub fn upgrade_credential(
-        ctx: Context<UpgradeCredential>,
-        new_name: String,
-        new_uri: String,
-        courses_completed: u32,
-        total_xp: state::I80F48,
+    pub fn complete_lesson<'info>(
+        ctx: Context<'_, '_, '_, 'info, CompleteLessonCompressed<'info>>,
+        lesson_index: u8,
+        proof: light_sdk::instruction::ValidityProof,
+        enrollment: state::CompressedEnrollment,
     ) -> Result<()> {
-        instructions::upgrade_credential::upgrade_credential(
+        instructions::complete_lesson_compressed::complete_lesson_compressed(
             ctx,
-            new_name,
-            new_uri,
-            courses_completed,
-            total_xp,
+            lesson_index,
+            proof,
+            enrollment,
         )
     }
 
-    pub fn reward_xp(
-        ctx: Context<RewardXp>,
-        amount: state::I80F48,
-        reason: String,
+    pub fn finalize_course<'info>(
+        ctx: Context<'_, '_, '_, 'info, FinalizeCourseCompressed<'info>>,
+        proof: light_sdk::instruction::ValidityProof,
+        enrollment: state::CompressedEnrollment,
     ) -> Result<()> {
-        instructions::reward_xp::reward_xp(ctx, amount, reason)
+        instructions::finalize_course_compressed::finalize_course_compressed(
+            ctx,
+            proof,
+            enrollment,
+        )
     }
 
-    pub fn award_achievement(
-        ctx: Context<AwardAchievement>,
+    pub fn award_achievement<'info>(
+        ctx: Context<'_, '_, '_, 'info, AwardAchievementCompressed<'info>>,
+        proof: light_sdk::instruction::ValidityProof,
+        address_tree_info: light_sdk::instruction::PackedAddressTreeInfo,
+        output_state_tree_index: u8,
     ) -> Result<()> {
-        instructions::award_achievement::award_achievement(ctx)
+        instructions::award_achievement_compressed::award_achievement_compressed(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+        )
     }
 }
@@ -1,25 +1,97 @@
 use anchor_lang::prelude::*;
+#[cfg(feature = "light")]
+use light_sdk::{instruction::account_meta::CompressedAccountMeta, LightDiscriminator};
 
 pub const MAX_COURSE_ID_LEN: usize = 64;
 pub const MAX_MINTER_LABEL_LEN: usize = 32;
+pub const MAX_BACKEND_SIGNER_LABEL_LEN: usize = 32;
+pub const MAX_BACKEND_SIGNERS: u8 = 4;
 pub const MAX_ACHIEVEMENT_ID_LEN: usize = 64;
 pub const MAX_ACHIEVEMENT_NAME_LEN: usize = 64;
 pub const MAX_METADATA_URI_LEN: usize = 200;
+pub const MAX_TOKEN_NAME_LEN: usize = 32;
+pub const MAX_TOKEN_SYMBOL_LEN: usize = 10;
+pub const MAX_REWARD_ID_LEN: usize = 64;
+pub const MAX_GUILD_ID_LEN: usize = 32;
+pub const MAX_GUILD_NAME_LEN: usize = 32;
 pub const MAX_LESSONS: u8 = 255;
+pub const BPS_DENOMINATOR: u16 = 10_000;
+pub const MAX_CO_CREATORS: usize = 8;
+pub const FINALIZE_ATTESTATION_INDEX: u8 = u8::MAX;
+pub const ATTESTATION_NONCE_WINDOW: u64 = 256;
+pub const LEADERBOARD_SIZE: usize = 32;
+pub const MAX_DISTRIBUTION_RECIPIENTS: u32 = 1024;
+pub const GUILD_SWITCH_COOLDOWN_SECS: i64 = 7 * 24 * 60 * 60;
+pub const MAX_MENTOR_LABEL_LEN: usize = 32;
+pub const MAX_MENTOR_COURSES: usize = 8;
+pub const MAX_MENTOR_REVIEW_XP: u32 = 100;
+// `Leaderboard::season` for the lifetime board.
+pub const LIFETIME_LEADERBOARD: u32 = 0;
 
+// Every account ends with `bump`, `version` and a zeroed `reserved` tail. New
+// fields go between `version` and `reserved`, shrinking `reserved` by the same
+// amount, so existing accounts decode them as zero without a resize.
+pub const CURRENT_ACCOUNT_VERSION: u8 = 2;
+// Version 2 switched `I80F48` fields from whole units to 80.48 fixed-point.
+pub const FIXED_POINT_ACCOUNT_VERSION: u8 = 2;
+pub const ACCOUNT_RESERVED_LEN: usize = 64;
+
+pub const COURSE_ROLE_UPDATE_CONTENT: u8 = 1 << 0;
+pub const COURSE_ROLE_TOGGLE_ACTIVE: u8 = 1 << 1;
+pub const COURSE_ROLE_ADJUST_REWARDS: u8 = 1 << 2;
+pub const COURSE_ROLE_ALL_PERMISSIONS: u8 =
+    COURSE_ROLE_UPDATE_CONTENT | COURSE_ROLE_TOGGLE_ACTIVE | COURSE_ROLE_ADJUST_REWARDS;
+
+// Signed 80.48 fixed-point: `value` holds the raw bits, so one whole unit is
+// `1 << 48`. Products and quotients truncate toward zero; conversions to token
+// units round down and reject negative values. Before account version 2 the
+// same field held whole units, see `from_legacy_units`.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct I80F48 {
     pub value: i128,
 }
 
 impl I80F48 {
+    pub const FRAC_BITS: u32 = 48;
+    pub const ZERO: Self = Self { value: 0 };
+    pub const ONE: Self = Self {
+        value: 1 << Self::FRAC_BITS,
+    };
+    pub const MAX: Self = Self { value: i128::MAX };
+
     pub fn from_u64(v: u64) -> Self {
-        Self { value: v as i128 }
+        Self {
+            value: (v as i128) << Self::FRAC_BITS,
+        }
+    }
+
+    // `numerator / denominator`, e.g. `from_ratio(150, 100)` for a 1.5x multiplier.
+    pub fn from_ratio(numerator: u64, denominator: u64) -> Result<Self> {
+        Self::from_u64(numerator).checked_div(Self::from_u64(denominator))
+    }
+
+    // Raw token units of a mint with `decimals` decimals to whole tokens.
+    pub fn from_token_amount(amount: u64, decimals: u8) -> Result<Self> {
+        Self::from_u64(amount).checked_div(Self::from_u64(pow10(decimals)?))
+    }
+
+    // Pre-version-2 accounts stored whole units in `value`. Saturates so the
+    // `i128::MAX` "no cap" sentinel stays uncapped.
+    pub fn from_legacy_units(value: i128) -> Self {
+        Self {
+            value: value.saturating_mul(Self::ONE.value),
+        }
     }
 
     pub fn as_u64(self) -> Result<u64> {
         require!(self.value >= 0, crate::errors::AcademyError::InvalidAmount);
-        u64::try_from(self.value).map_err(|_| error!(crate::errors::AcademyError::InvalidAmount))
+        u64::try_from(self.value >> Self::FRAC_BITS)
+            .map_err(|_| error!(crate::errors::AcademyError::InvalidAmount))
+    }
+
+    // Whole tokens to raw units of a mint with `decimals` decimals, rounded down.
+    pub fn to_token_amount(self, decimals: u8) -> Result<u64> {
+        self.checked_mul(Self::from_u64(pow10(decimals)?))?.as_u64()
     }
 
     pub fn checked_add(self, rhs: Self) -> Result<Self> {
@@ -28,11 +100,212 @@ impl I80F48 {
             .map(|value| Self { value })
             .ok_or_else(|| error!(crate::errors::AcademyError::Overflow))
     }
+
+    pub fn checked_sub(self, rhs: Self) -> Result<Self> {
+        self.value
+            .checked_sub(rhs.value)
+            .map(|value| Self { value })
+            .ok_or_else(|| error!(crate::errors::AcademyError::Overflow))
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Result<Self> {
+        let magnitude = mul_shr_frac(self.value.unsigned_abs(), rhs.value.unsigned_abs());
+        Self::from_magnitude(magnitude, (self.value < 0) != (rhs.value < 0))
+    }
+
+    pub fn checked_div(self, rhs: Self) -> Result<Self> {
+        require!(rhs.value != 0, crate::errors::AcademyError::InvalidAmount);
+        let magnitude = shl_frac_div(self.value.unsigned_abs(), rhs.value.unsigned_abs());
+        Self::from_magnitude(magnitude, (self.value < 0) != (rhs.value < 0))
+    }
+
+    // A negative magnitude may be one larger than a positive one: `2^127`
+    // negates to `i128::MIN`.
+    fn from_magnitude(magnitude: Option<u128>, negative: bool) -> Result<Self> {
+        magnitude
+            .and_then(|m| match negative {
+                true => 0i128.checked_sub_unsigned(m),
+                false => i128::try_from(m).ok(),
+            })
+            .map(|value| Self { value })
+            .ok_or_else(|| error!(crate::errors::AcademyError::Overflow))
+    }
+}
+
+fn pow10(decimals: u8) -> Result<u64> {
+    10u64
+        .checked_pow(decimals as u32)
+        .ok_or_else(|| error!(crate::errors::AcademyError::Overflow))
+}
+
+// `(a * b) >> 48` through a 256-bit intermediate.
+fn mul_shr_frac(a: u128, b: u128) -> Option<u128> {
+    const LOW: u128 = u64::MAX as u128;
+    let (a0, a1) = (a & LOW, a >> 64);
+    let (b0, b1) = (b & LOW, b >> 64);
+
+    let (mid, mid_carry) = (a0 * b1).overflowing_add(a1 * b0);
+    let (lo, lo_carry) = (a0 * b0).overflowing_add(mid << 64);
+    let hi = (a1 * b1)
+        .checked_add(mid >> 64)?
+        .checked_add((mid_carry as u128) << 64)?
+        .checked_add(lo_carry as u128)?;
+
+    if hi >> I80F48::FRAC_BITS != 0 {
+        return None;
+    }
+    Some((hi << (128 - I80F48::FRAC_BITS)) | (lo >> I80F48::FRAC_BITS))
+}
+
+// `(a << 48) / b`: whole quotient first, then the fractional bits by long
+// division of the remainder. `b <= 2^127`, so the doubled remainder never
+// overflows.
+fn shl_frac_div(a: u128, b: u128) -> Option<u128> {
+    let whole = a / b;
+    if whole >> (128 - I80F48::FRAC_BITS) != 0 {
+        return None;
+    }
+    let mut remainder = a % b;
+    let mut fraction = 0u128;
+    for _ in 0..I80F48::FRAC_BITS {
+        // `remainder < b`, so the doubled value needs 129 bits when `b` is
+        // above 2^127; the shifted-out bit means it certainly exceeds `b`.
+        let carry = remainder >> 127 != 0;
+        remainder <<= 1;
+        fraction <<= 1;
+        if carry || remainder >= b {
+            remainder = remainder.wrapping_sub(b);
+            fraction |= 1;
+        }
+    }
+    Some((whole << I80F48::FRAC_BITS) | fraction)
+}
+
+// Accounts `migrate_account` knows how to upgrade in place.
+pub trait Versioned: AccountSerialize + AccountDeserialize {
+    const SPACE: usize;
+
+    fn version(&self) -> u8;
+
+    fn set_version(&mut self, version: u8);
+
+    // Learner-owned accounts may also be migrated by the learner.
+    fn owner(&self) -> Option<Pubkey> {
+        None
+    }
+
+    // Rewrites fields whose encoding changed since `from_version`.
+    fn upgrade(&mut self, _from_version: u8) -> Result<()> {
+        Ok(())
+    }
+
+    // Decodes a pre-versioning layout, recognised by its exact size.
+    fn from_legacy(_data: &[u8]) -> Result<Option<Self>> {
+        Ok(None)
+    }
+}
+
+macro_rules! impl_versioned {
+    (
+        $account:ident
+        $(, owner = $owner:ident)?
+        $(, upgrade = $upgrade:ident)?
+        $(, legacy = $legacy:ident)?
+    ) => {
+        impl Versioned for $account {
+            const SPACE: usize = 8 + $account::LEN;
+
+            fn version(&self) -> u8 {
+                self.version
+            }
+
+            fn set_version(&mut self, version: u8) {
+                self.version = version;
+            }
+
+            $(
+                fn owner(&self) -> Option<Pubkey> {
+                    Some(self.$owner)
+                }
+            )?
+
+            $(
+                fn upgrade(&mut self, from_version: u8) -> Result<()> {
+                    self.$upgrade(from_version)
+                }
+            )?
+
+            $(
+                fn from_legacy(data: &[u8]) -> Result<Option<Self>> {
+                    if data.len() != 8 + $legacy::LEN {
+                        return Ok(None);
+                    }
+                    let legacy = $legacy::deserialize(&mut &data[8..])
+                        .map_err(|_| error!(crate::errors::AcademyError::AccountNotMigratable))?;
+                    Ok(Some(legacy.into()))
+                }
+            )?
+        }
+    };
+}
+
+impl_versioned!(Config, legacy = LegacyConfigV0);
+impl_versioned!(BackendSignerRole, upgrade = upgrade_fixed_point);
+impl_versioned!(Course, legacy = LegacyCourseV0);
+impl_versioned!(CreatorVault);
+impl_versioned!(CourseSubmission);
+impl_versioned!(ReviewerRole);
+impl_versioned!(Enrollment, owner = learner, legacy = LegacyEnrollmentV0);
+impl_versioned!(EnrollmentArchive, owner = learner);
+impl_versioned!(CompletionRecord, owner = learner);
+impl_versioned!(CourseRole);
+impl_versioned!(Cohort);
+impl_versioned!(CohortWaitlistEntry, owner = learner);
+impl_versioned!(
+    MinterRole,
+    upgrade = upgrade_fixed_point,
+    legacy = LegacyMinterRoleV0
+);
+impl_versioned!(AchievementType, legacy = LegacyAchievementTypeV0);
+impl_versioned!(
+    AchievementReceipt,
+    owner = recipient,
+    legacy = LegacyAchievementReceiptV0
+);
+impl_versioned!(AttestationNonces, owner = learner);
+impl_versioned!(Season);
+impl_versioned!(Leaderboard);
+impl_versioned!(RewardDistribution);
+impl_versioned!(Guild);
+impl_versioned!(GuildMembership, owner = learner);
+impl_versioned!(MentorRole);
+impl_versioned!(MentorReview, owner = learner);
+impl_versioned!(RewardItem);
+impl_versioned!(RedemptionTally, owner = learner);
+impl_versioned!(Redemption, owner = learner);
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CourseStatus {
+    Approved,
+    Pending,
+    // Left by courses rejected before `reject_course` closed them.
+    Rejected,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct InitializeParams {
+    pub backend_signer: Pubkey,
+    pub xp_name: String,
+    pub xp_symbol: String,
+    pub xp_uri: String,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
 pub struct ConfigUpdate {
-    pub new_backend_signer: Option<Pubkey>,
+    pub new_treasury: Option<Pubkey>,
+    pub new_submission_deposit: Option<u64>,
+    pub new_rejection_slash_bps: Option<u16>,
+    pub new_scholarship_pool: Option<Pubkey>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -48,6 +321,35 @@ pub struct CreateCourseParams {
     pub prerequisite: Option<Pubkey>,
     pub creator_reward_xp: u32,
     pub min_completions_for_reward: u32,
+    pub pricing: Option<CoursePricing>,
+    pub commitment: Option<CommitmentPolicy>,
+    pub max_duration_secs: Option<i64>,
+    pub allow_progress_restore: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CommitmentPolicy {
+    // `None` means the stake is posted in SOL.
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub deadline_secs: i64,
+}
+
+impl CommitmentPolicy {
+    pub const LEN: usize = (1 + 32) + 8 + 8;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CoursePricing {
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub refund_window_secs: i64,
+    pub refund_max_lessons: u8,
+    pub creator_share_bps: u16,
+}
+
+impl CoursePricing {
+    pub const LEN: usize = 32 + 8 + 8 + 1 + 2;
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
@@ -57,6 +359,59 @@ pub struct CourseUpdate {
     pub new_xp_per_lesson: Option<u32>,
     pub new_creator_reward_xp: Option<u32>,
     pub new_min_completions_for_reward: Option<u32>,
+    pub new_price_amount: Option<u64>,
+    pub new_allow_progress_restore: Option<bool>,
+    pub new_mentor_graded_lessons: Option<[u64; 4]>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AddBackendSignerParams {
+    pub signer: Pubkey,
+    pub label: String,
+    pub valid_from: i64,
+    pub valid_until: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct BackendSignerUpdate {
+    pub new_valid_from: Option<i64>,
+    pub new_valid_until: Option<i64>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GrantCourseRoleParams {
+    pub holder: Pubkey,
+    pub permissions: u8,
+    pub max_xp_per_lesson: u32,
+    pub max_creator_reward_xp: u32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct CreatorSplit {
+    pub recipient: Pubkey,
+    pub bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct CreatorShare {
+    pub recipient: Pubkey,
+    pub bps: u16,
+    pub pending_xp: u64,
+    pub claimed_xp: u64,
+}
+
+impl CreatorShare {
+    pub const LEN: usize = 32 + 2 + 8 + 8;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RegisterMentorParams {
+    pub mentor: Pubkey,
+    pub label: String,
+    pub courses: Vec<Pubkey>,
+    pub max_reviews_per_window: u32,
+    pub window_secs: i64,
+    pub review_xp: u32,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -64,6 +419,53 @@ pub struct RegisterMinterParams {
     pub minter: Pubkey,
     pub label: String,
     pub max_xp_per_call: I80F48,
+    pub max_xp_per_window: I80F48,
+    pub window_secs: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct CreateCohortParams {
+    pub cohort_id: u32,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub capacity: u32,
+    pub waitlist_enabled: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CreateSeasonParams {
+    pub season: u32,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CreateGuildParams {
+    pub guild_id: String,
+    pub name: String,
+    pub member_cap: u32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct CreateDistributionParams {
+    pub season: u32,
+    pub merkle_root: [u8; 32],
+    pub total_amount: u64,
+    pub num_recipients: u32,
+    pub expires_at: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CreateRewardItemParams {
+    pub reward_id: String,
+    pub metadata_uri: String,
+    pub price_xp: u64,
+    pub max_supply: u32,
+    pub per_learner_limit: u32,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub payout: Option<RewardPayout>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -78,13 +480,91 @@ pub struct CreateAchievementTypeParams {
 #[account]
 pub struct Config {
     pub authority: Pubkey,
-    pub backend_signer: Pubkey,
+    pub backend_signer_count: u8,
     pub xp_mint: Pubkey,
+    pub treasury: Pubkey,
+    pub submission_deposit: u64,
+    pub rejection_slash_bps: u16,
+    pub scholarship_pool: Pubkey,
     pub bump: u8,
+    pub version: u8,
+    pub current_season: u32,
+    pub season_mint: Pubkey,
+    pub season_active: bool,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN - 37],
 }
 
 impl Config {
-    pub const LEN: usize = 32 + 32 + 32 + 1;
+    pub const LEN: usize =
+        32 + 1 + 32 + 32 + 8 + 2 + 32 + 1 + 1 + 4 + 32 + 1 + (ACCOUNT_RESERVED_LEN - 37);
+}
+
+#[account]
+pub struct BackendSignerRole {
+    pub signer: Pubkey,
+    pub label: String,
+    pub valid_from: i64,
+    pub valid_until: i64,
+    pub total_xp_minted: I80F48,
+    pub created_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    // Removed signers are deactivated rather than closed so their
+    // `total_xp_minted` stays on record for clawbacks and audits.
+    pub is_active: bool,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN - 1],
+}
+
+impl BackendSignerRole {
+    pub const LEN: usize =
+        32 + (4 + MAX_BACKEND_SIGNER_LABEL_LEN) + 8 + 8 + 16 + 8 + 1 + 1 + ACCOUNT_RESERVED_LEN;
+
+    pub fn new(
+        signer: Pubkey,
+        label: String,
+        valid_from: i64,
+        valid_until: i64,
+        created_at: i64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            signer,
+            label,
+            valid_from,
+            valid_until,
+            total_xp_minted: I80F48::ZERO,
+            created_at,
+            bump,
+            version: CURRENT_ACCOUNT_VERSION,
+            is_active: true,
+            reserved: [0; ACCOUNT_RESERVED_LEN - 1],
+        }
+    }
+
+    pub fn is_valid_at(&self, now: i64) -> bool {
+        self.is_active && self.valid_from <= now && now < self.valid_until
+    }
+
+    pub fn require_fixed_point(&self) -> Result<()> {
+        require!(
+            self.version >= FIXED_POINT_ACCOUNT_VERSION,
+            crate::errors::AcademyError::AccountNeedsMigration
+        );
+        Ok(())
+    }
+
+    fn upgrade_fixed_point(&mut self, from_version: u8) -> Result<()> {
+        if from_version < FIXED_POINT_ACCOUNT_VERSION {
+            self.total_xp_minted = I80F48::from_legacy_units(self.total_xp_minted.value);
+        }
+        Ok(())
+    }
+
+    pub fn record_mint(&mut self, amount: u64) -> Result<()> {
+        self.require_fixed_point()?;
+        self.total_xp_minted = self.total_xp_minted.checked_add(I80F48::from_u64(amount))?;
+        Ok(())
+    }
 }
 
 #[account]
@@ -102,8 +582,19 @@ pub struct Course {
     pub min_completions_for_reward: u32,
     pub completion_count: u32,
     pub is_active: bool,
+    pub status: CourseStatus,
+    pub pricing: Option<CoursePricing>,
+    pub commitment: Option<CommitmentPolicy>,
+    pub max_duration_secs: Option<i64>,
+    pub allow_progress_restore: bool,
+    pub pending_creator_revenue: u64,
+    pub pending_treasury_revenue: u64,
     pub created_at: i64,
     pub bump: u8,
+    pub version: u8,
+    // Lessons only a course mentor can sign off; see `MentorRole`.
+    pub mentor_graded_lessons: [u64; 4],
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN - 32],
 }
 
 impl Course {
@@ -120,11 +611,203 @@ impl Course {
         + 4
         + 4
         + 1
+        + 1
+        + (1 + CoursePricing::LEN)
+        + (1 + CommitmentPolicy::LEN)
+        + (1 + 8)
+        + 1
         + 8
-        + 1;
+        + 8
+        + 8
+        + 1
+        + 1
+        + 32
+        + (ACCOUNT_RESERVED_LEN - 32);
+
+    pub fn is_mentor_graded(&self, lesson_index: u8) -> bool {
+        let word_index = (lesson_index / 64) as usize;
+        let bit_index = lesson_index % 64;
+        ((self.mentor_graded_lessons[word_index] >> bit_index) & 1) == 1
+    }
+
+    pub fn has_mentor_graded(&self, lesson_mask: &[u64; 4]) -> bool {
+        lesson_mask
+            .iter()
+            .zip(self.mentor_graded_lessons.iter())
+            .any(|(mask, graded)| mask & graded != 0)
+    }
+
+    pub fn base_xp(&self) -> Result<u64> {
+        u64::from(self.xp_per_lesson)
+            .checked_mul(u64::from(self.lesson_count))
+            .ok_or_else(|| error!(crate::errors::AcademyError::Overflow))
+    }
+
+    pub fn completion_bonus_xp(&self) -> Result<u64> {
+        Ok(self.base_xp()? / 2)
+    }
+
+    pub fn record_completion(&mut self) -> Result<u32> {
+        self.completion_count = self
+            .completion_count
+            .checked_add(1)
+            .ok_or_else(|| error!(crate::errors::AcademyError::Overflow))?;
+        if self.completion_count >= self.min_completions_for_reward {
+            Ok(self.creator_reward_xp)
+        } else {
+            Ok(0)
+        }
+    }
+
+    // Moves an enrollment's escrowed fee into the creator/treasury buckets that
+    // `withdraw_course_revenue` pays out.
+    pub fn release_revenue(&mut self, amount: u64) -> Result<()> {
+        let creator_share_bps = self.pricing.map_or(0, |pricing| pricing.creator_share_bps);
+        let creator_part = u64::try_from(
+            u128::from(amount) * u128::from(creator_share_bps) / u128::from(BPS_DENOMINATOR),
+        )
+        .map_err(|_| error!(crate::errors::AcademyError::Overflow))?;
+        self.pending_creator_revenue = self
+            .pending_creator_revenue
+            .checked_add(creator_part)
+            .ok_or_else(|| error!(crate::errors::AcademyError::Overflow))?;
+        self.pending_treasury_revenue = self
+            .pending_treasury_revenue
+            .checked_add(amount - creator_part)
+            .ok_or_else(|| error!(crate::errors::AcademyError::Overflow))?;
+        Ok(())
+    }
+}
+
+#[account]
+pub struct CreatorVault {
+    pub course: Pubkey,
+    pub shares: Vec<CreatorShare>,
+    pub total_accrued_xp: u64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl CreatorVault {
+    pub const LEN: usize =
+        32 + (4 + MAX_CO_CREATORS * CreatorShare::LEN) + 8 + 1 + 1 + ACCOUNT_RESERVED_LEN;
+
+    // A new vault pays the course creator in full until splits are set.
+    pub fn new(course: Pubkey, creator: Pubkey, bump: u8) -> Self {
+        Self {
+            course,
+            shares: vec![CreatorShare {
+                recipient: creator,
+                bps: BPS_DENOMINATOR,
+                pending_xp: 0,
+                claimed_xp: 0,
+            }],
+            total_accrued_xp: 0,
+            bump,
+            version: CURRENT_ACCOUNT_VERSION,
+            reserved: [0; ACCOUNT_RESERVED_LEN],
+        }
+    }
+
+    pub fn accrue(&mut self, amount: u64) -> Result<()> {
+        let mut distributed: u64 = 0;
+        for share in self.shares.iter_mut() {
+            let portion = u64::try_from(
+                u128::from(amount) * u128::from(share.bps) / u128::from(BPS_DENOMINATOR),
+            )
+            .map_err(|_| error!(crate::errors::AcademyError::Overflow))?;
+            share.pending_xp = share
+                .pending_xp
+                .checked_add(portion)
+                .ok_or_else(|| error!(crate::errors::AcademyError::Overflow))?;
+            distributed += portion;
+        }
+
+        // Rounding dust goes to the first (primary) creator.
+        if let Some(primary) = self.shares.first_mut() {
+            primary.pending_xp = primary
+                .pending_xp
+                .checked_add(amount - distributed)
+                .ok_or_else(|| error!(crate::errors::AcademyError::Overflow))?;
+        }
+
+        self.total_accrued_xp = self
+            .total_accrued_xp
+            .checked_add(amount)
+            .ok_or_else(|| error!(crate::errors::AcademyError::Overflow))?;
+        Ok(())
+    }
+
+    // Recipients dropped from the splits keep what they have accrued: they
+    // stay with no share of future rewards until `claim_creator_reward` pays
+    // them out. New splits go first so the primary creator still gets the dust.
+    pub fn set_splits(&mut self, splits: &[CreatorSplit]) -> Result<()> {
+        let mut shares: Vec<CreatorShare> = splits
+            .iter()
+            .map(|split| {
+                let previous = self
+                    .shares
+                    .iter()
+                    .find(|share| share.recipient == split.recipient);
+                CreatorShare {
+                    recipient: split.recipient,
+                    bps: split.bps,
+                    pending_xp: previous.map_or(0, |share| share.pending_xp),
+                    claimed_xp: previous.map_or(0, |share| share.claimed_xp),
+                }
+            })
+            .collect();
+        for existing in self.shares.iter() {
+            let retained = splits
+                .iter()
+                .any(|split| split.recipient == existing.recipient);
+            if !retained && existing.pending_xp > 0 {
+                shares.push(CreatorShare {
+                    bps: 0,
+                    ..*existing
+                });
+            }
+        }
+        require!(
+            shares.len() <= MAX_CO_CREATORS,
+            crate::errors::AcademyError::CreatorRewardPending
+        );
+        self.shares = shares;
+        Ok(())
+    }
 }
 
 #[account]
+pub struct CourseSubmission {
+    pub course: Pubkey,
+    pub submitter: Pubkey,
+    pub deposit: u64,
+    pub submitted_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl CourseSubmission {
+    pub const LEN: usize = 32 + 32 + 8 + 8 + 1 + 1 + ACCOUNT_RESERVED_LEN;
+}
+
+#[account]
+pub struct ReviewerRole {
+    pub reviewer: Pubkey,
+    pub created_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl ReviewerRole {
+    pub const LEN: usize = 32 + 8 + 1 + 1 + ACCOUNT_RESERVED_LEN;
+}
+
+#[account]
+#[cfg_attr(feature = "light", derive(LightDiscriminator))]
 pub struct Enrollment {
     pub course_id: String,
     pub learner: Pubkey,
@@ -132,11 +815,35 @@ pub struct Enrollment {
     pub enrolled_at: i64,
     pub completed_at: Option<i64>,
     pub credential_asset: Option<Pubkey>,
+    pub escrowed_amount: u64,
+    pub commitment_amount: u64,
+    pub commitment_deadline: i64,
+    pub expires_at: Option<i64>,
+    pub cohort: Option<Pubkey>,
     pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
 }
 
 impl Enrollment {
-    pub const LEN: usize = (4 + MAX_COURSE_ID_LEN) + 32 + 32 + 8 + (1 + 8) + (1 + 32) + 1;
+    pub const LEN: usize = (4 + MAX_COURSE_ID_LEN)
+        + 32
+        + 32
+        + 8
+        + (1 + 8)
+        + (1 + 32)
+        + 8
+        + 8
+        + 8
+        + (1 + 8)
+        + (1 + 32)
+        + 1
+        + 1
+        + ACCOUNT_RESERVED_LEN;
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at.is_some_and(|expires_at| now > expires_at)
+    }
 
     pub fn is_lesson_complete(&self, lesson_index: u8) -> bool {
         let word_index = (lesson_index / 64) as usize;
@@ -155,6 +862,36 @@ impl Enrollment {
         Ok(())
     }
 
+    pub fn set_lessons_complete(&mut self, mask: [u64; 4], lesson_count: u8) -> Result<Vec<u8>> {
+        require!(
+            mask.iter().any(|word| *word != 0),
+            crate::errors::AcademyError::EmptyLessonMask
+        );
+
+        let mut lesson_indices = Vec::new();
+        for (word_index, word) in mask.iter().enumerate() {
+            require!(
+                word & self.lesson_flags[word_index] == 0,
+                crate::errors::AcademyError::LessonAlreadyCompleted
+            );
+            for bit_index in 0..64u16 {
+                if (word >> bit_index) & 1 == 1 {
+                    let lesson_index = word_index as u16 * 64 + bit_index;
+                    require!(
+                        lesson_index < u16::from(lesson_count),
+                        crate::errors::AcademyError::LessonOutOfBounds
+                    );
+                    lesson_indices.push(lesson_index as u8);
+                }
+            }
+        }
+
+        for (flags, word) in self.lesson_flags.iter_mut().zip(mask.iter()) {
+            *flags |= word;
+        }
+        Ok(lesson_indices)
+    }
+
     pub fn completed_lesson_count(&self) -> u32 {
         self.lesson_flags.iter().map(|w| w.count_ones()).sum()
     }
@@ -164,57 +901,1617 @@ impl Enrollment {
     }
 }
 
-#[account]
-pub struct MinterRole {
-    pub minter: Pubkey,
-    pub label: String,
-    pub max_xp_per_call: I80F48,
-    pub total_xp_minted: I80F48,
-    pub is_active: bool,
-    pub created_at: i64,
-    pub bump: u8,
+#[cfg(feature = "light")]
+impl Default for Enrollment {
+    fn default() -> Self {
+        Self {
+            course_id: String::new(),
+            learner: Pubkey::default(),
+            lesson_flags: [0; 4],
+            enrolled_at: 0,
+            completed_at: None,
+            credential_asset: None,
+            escrowed_amount: 0,
+            commitment_amount: 0,
+            commitment_deadline: 0,
+            expires_at: None,
+            cohort: None,
+            bump: 0,
+            version: 0,
+            reserved: [0; ACCOUNT_RESERVED_LEN],
+        }
+    }
 }
 
-impl MinterRole {
-    pub const LEN: usize = 32 + (4 + MAX_MINTER_LABEL_LEN) + 16 + 16 + 1 + 8 + 1;
+// A compressed enrollment as read by the client, passed back in with the
+// tree position its validity proof refers to.
+#[cfg(feature = "light")]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CompressedEnrollment {
+    pub meta: CompressedAccountMeta,
+    pub enrollment: Enrollment,
 }
 
+// Survives `close_enrollment` so a learner's history (and best progress) is
+// kept across attempts.
 #[account]
-pub struct AchievementType {
-    pub achievement_id: String,
-    pub name: String,
-    pub metadata_uri: String,
-    pub collection: Pubkey,
-    pub current_supply: u32,
-    pub max_supply: u32,
-    pub xp_reward: u32,
-    pub is_active: bool,
-    pub created_at: i64,
+pub struct EnrollmentArchive {
+    pub course_id: String,
+    pub learner: Pubkey,
+    pub attempt_count: u32,
+    pub best_lesson_flags: [u64; 4],
+    pub first_enrolled_at: i64,
+    pub last_enrolled_at: i64,
+    pub last_closed_at: i64,
+    pub completed_at: Option<i64>,
     pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
 }
 
-impl AchievementType {
-    pub const LEN: usize = (4 + MAX_ACHIEVEMENT_ID_LEN)
-        + (4 + MAX_ACHIEVEMENT_NAME_LEN)
-        + (4 + MAX_METADATA_URI_LEN)
-        + 32
-        + 4
-        + 4
-        + 4
-        + 1
-        + 8
-        + 1;
+impl EnrollmentArchive {
+    pub const LEN: usize =
+        (4 + MAX_COURSE_ID_LEN) + 32 + 4 + 32 + 8 + 8 + 8 + (1 + 8) + 1 + 1 + ACCOUNT_RESERVED_LEN;
+
+    pub fn best_lesson_count(&self) -> u32 {
+        self.best_lesson_flags.iter().map(|w| w.count_ones()).sum()
+    }
+
+    // Lesson flags a new enrollment starts from, given the raw archive PDA
+    // (empty when the learner has never closed an attempt). Every close writes
+    // the archive, so a finished attempt found here blocks re-enrolling and
+    // with it a second round of lesson, bonus and creator XP.
+    pub fn restored_lesson_flags(data: &[u8], allow_progress_restore: bool) -> Result<[u64; 4]> {
+        if data.is_empty() {
+            return Ok([0u64; 4]);
+        }
+        let archive = Self::try_deserialize(&mut &data[..])?;
+        require!(
+            archive.completed_at.is_none(),
+            crate::errors::AcademyError::ArchiveAlreadyCompleted
+        );
+        Ok(match allow_progress_restore {
+            true => archive.best_lesson_flags,
+            false => [0u64; 4],
+        })
+    }
+
+    // Folds a closing enrollment into the archive, initialising it on the
+    // first attempt. Every path that closes an enrollment goes through here.
+    pub fn record_attempt(&mut self, enrollment: &Enrollment, bump: u8, now: i64) -> Result<()> {
+        if self.attempt_count == 0 {
+            self.course_id = enrollment.course_id.clone();
+            self.learner = enrollment.learner;
+            self.first_enrolled_at = enrollment.enrolled_at;
+            self.bump = bump;
+            self.version = CURRENT_ACCOUNT_VERSION;
+        }
+        self.attempt_count = self
+            .attempt_count
+            .checked_add(1)
+            .ok_or_else(|| error!(crate::errors::AcademyError::Overflow))?;
+        if enrollment.completed_lesson_count() > self.best_lesson_count() {
+            self.best_lesson_flags = enrollment.lesson_flags;
+        }
+        self.last_enrolled_at = enrollment.enrolled_at;
+        self.last_closed_at = now;
+        if self.completed_at.is_none() {
+            self.completed_at = enrollment.completed_at;
+        }
+        Ok(())
+    }
 }
 
+// Minimal proof of completion left behind by `compact_enrollment`; accepted
+// wherever a finalized `Enrollment` used to be required.
 #[account]
-pub struct AchievementReceipt {
-    pub achievement_id: String,
+pub struct CompletionRecord {
+    pub course: Pubkey,
+    pub learner: Pubkey,
+    pub completed_at: i64,
+    pub credential: Option<Pubkey>,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl CompletionRecord {
+    pub const LEN: usize = 32 + 32 + 8 + (1 + 32) + 1 + 1 + ACCOUNT_RESERVED_LEN;
+}
+
+#[account]
+pub struct CourseRole {
+    pub course: Pubkey,
+    pub holder: Pubkey,
+    pub permissions: u8,
+    pub max_xp_per_lesson: u32,
+    pub max_creator_reward_xp: u32,
+    pub granted_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl CourseRole {
+    pub const LEN: usize = 32 + 32 + 1 + 4 + 4 + 8 + 1 + 1 + ACCOUNT_RESERVED_LEN;
+
+    pub fn has_permission(&self, permission: u8) -> bool {
+        self.permissions & permission == permission
+    }
+}
+
+#[account]
+pub struct Cohort {
+    pub course: Pubkey,
+    pub cohort_id: u32,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub capacity: u32,
+    pub enrolled_count: u32,
+    pub completed_count: u32,
+    pub waitlist_enabled: bool,
+    pub waitlist_count: u32,
+    pub created_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    // Seats held by enrollments that haven't been closed yet, unlike the
+    // cumulative `enrolled_count`.
+    pub occupied_seats: u32,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN - 4],
+}
+
+impl Cohort {
+    pub const LEN: usize = 32 + 4 + 8 + 8 + 4 + 4 + 4 + 1 + 4 + 8 + 1 + 1 + ACCOUNT_RESERVED_LEN;
+
+    pub fn is_full(&self) -> bool {
+        self.occupied_seats >= self.capacity
+    }
+
+    pub fn take_seat(&mut self) -> Result<()> {
+        self.enrolled_count = self
+            .enrolled_count
+            .checked_add(1)
+            .ok_or_else(|| error!(crate::errors::AcademyError::Overflow))?;
+        self.occupied_seats = self
+            .occupied_seats
+            .checked_add(1)
+            .ok_or_else(|| error!(crate::errors::AcademyError::Overflow))?;
+        Ok(())
+    }
+
+    pub fn record_completion(&mut self) -> Result<()> {
+        self.completed_count = self
+            .completed_count
+            .checked_add(1)
+            .ok_or_else(|| error!(crate::errors::AcademyError::Overflow))?;
+        Ok(())
+    }
+
+    // Frees the seat held by an enrollment that is being closed.
+    pub fn release_seat(&mut self) {
+        self.occupied_seats = self.occupied_seats.saturating_sub(1);
+    }
+
+    // Drops a waitlist entry that was left or promoted to an enrollment.
+    pub fn leave_waitlist(&mut self) {
+        self.waitlist_count = self.waitlist_count.saturating_sub(1);
+    }
+}
+
+// One learner's place on a full cohort's waitlist.
+#[account]
+pub struct CohortWaitlistEntry {
+    pub cohort: Pubkey,
+    pub learner: Pubkey,
+    pub joined_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl CohortWaitlistEntry {
+    pub const LEN: usize = 32 + 32 + 8 + 1 + 1 + ACCOUNT_RESERVED_LEN;
+}
+
+#[account]
+pub struct MinterRole {
+    pub minter: Pubkey,
+    pub label: String,
+    pub max_xp_per_call: I80F48,
+    pub total_xp_minted: I80F48,
+    pub max_xp_per_window: I80F48,
+    pub window_secs: i64,
+    pub window_start: i64,
+    pub window_minted: I80F48,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl MinterRole {
+    pub const LEN: usize =
+        32 + (4 + MAX_MINTER_LABEL_LEN) + 16 + 16 + 16 + 8 + 8 + 16 + 1 + 8 + 1 + 1
+        + ACCOUNT_RESERVED_LEN;
+
+    pub fn require_fixed_point(&self) -> Result<()> {
+        require!(
+            self.version >= FIXED_POINT_ACCOUNT_VERSION,
+            crate::errors::AcademyError::AccountNeedsMigration
+        );
+        Ok(())
+    }
+
+    fn upgrade_fixed_point(&mut self, from_version: u8) -> Result<()> {
+        if from_version < FIXED_POINT_ACCOUNT_VERSION {
+            self.max_xp_per_call = I80F48::from_legacy_units(self.max_xp_per_call.value);
+            self.total_xp_minted = I80F48::from_legacy_units(self.total_xp_minted.value);
+            self.max_xp_per_window = I80F48::from_legacy_units(self.max_xp_per_window.value);
+            self.window_minted = I80F48::from_legacy_units(self.window_minted.value);
+        }
+        Ok(())
+    }
+
+    // A `window_secs` of zero disables the rolling cap.
+    pub fn consume_window(&mut self, amount: I80F48, now: i64) -> Result<()> {
+        self.require_fixed_point()?;
+        if self.window_secs == 0 {
+            return Ok(());
+        }
+        if now.saturating_sub(self.window_start) >= self.window_secs {
+            self.window_start = now;
+            self.window_minted = I80F48::ZERO;
+        }
+        let minted = self.window_minted.checked_add(amount)?;
+        require!(
+            minted.value <= self.max_xp_per_window.value,
+            crate::errors::AcademyError::MinterWindowExceeded
+        );
+        self.window_minted = minted;
+        Ok(())
+    }
+}
+
+// Course-scoped grader for mentor-graded lessons. `window_secs` of zero
+// disables the rolling review cap; `review_xp` of zero pays nothing.
+#[account]
+pub struct MentorRole {
+    pub mentor: Pubkey,
+    pub label: String,
+    pub courses: Vec<Pubkey>,
+    pub max_reviews_per_window: u32,
+    pub window_secs: i64,
+    pub window_start: i64,
+    pub window_reviews: u32,
+    pub review_xp: u32,
+    pub total_reviews: u64,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl MentorRole {
+    pub const LEN: usize = 32
+        + (4 + MAX_MENTOR_LABEL_LEN)
+        + (4 + MAX_MENTOR_COURSES * 32)
+        + 4
+        + 8
+        + 8
+        + 4
+        + 4
+        + 8
+        + 1
+        + 8
+        + 1
+        + 1
+        + ACCOUNT_RESERVED_LEN;
+
+    pub fn covers(&self, course: &Pubkey) -> bool {
+        self.courses.contains(course)
+    }
+
+    pub fn consume_review(&mut self, now: i64) -> Result<()> {
+        if self.window_secs > 0 {
+            if now.saturating_sub(self.window_start) >= self.window_secs {
+                self.window_start = now;
+                self.window_reviews = 0;
+            }
+            require!(
+                self.window_reviews < self.max_reviews_per_window,
+                crate::errors::AcademyError::MentorWindowExceeded
+            );
+            self.window_reviews += 1;
+        }
+        self.total_reviews = self
+            .total_reviews
+            .checked_add(1)
+            .ok_or_else(|| error!(crate::errors::AcademyError::Overflow))?;
+        Ok(())
+    }
+}
+
+// Completion record for a mentor-graded lesson: who signed it off and when.
+// Keyed by the enrollment's `enrolled_at`, so each attempt gets its own record.
+#[account]
+pub struct MentorReview {
+    pub course: Pubkey,
+    pub learner: Pubkey,
+    pub enrolled_at: i64,
+    pub lesson_index: u8,
+    pub mentor: Pubkey,
+    pub xp_earned: u32,
+    pub mentor_xp: u32,
+    pub reviewed_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl MentorReview {
+    pub const LEN: usize = 32 + 32 + 8 + 1 + 32 + 4 + 4 + 8 + 1 + 1 + ACCOUNT_RESERVED_LEN;
+}
+
+#[account]
+pub struct AchievementType {
+    pub achievement_id: String,
+    pub name: String,
+    pub metadata_uri: String,
+    pub collection: Pubkey,
+    pub current_supply: u32,
+    pub max_supply: u32,
+    pub xp_reward: u32,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl AchievementType {
+    pub const LEN: usize = (4 + MAX_ACHIEVEMENT_ID_LEN)
+        + (4 + MAX_ACHIEVEMENT_NAME_LEN)
+        + (4 + MAX_METADATA_URI_LEN)
+        + 32
+        + 4
+        + 4
+        + 4
+        + 1
+        + 8
+        + 1
+        + 1
+        + ACCOUNT_RESERVED_LEN;
+}
+
+#[account]
+#[cfg_attr(feature = "light", derive(LightDiscriminator))]
+pub struct AchievementReceipt {
+    pub achievement_id: String,
     pub recipient: Pubkey,
     pub asset: Pubkey,
     pub awarded_at: i64,
     pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
 }
 
 impl AchievementReceipt {
+    pub const LEN: usize =
+        (4 + MAX_ACHIEVEMENT_ID_LEN) + 32 + 32 + 8 + 1 + 1 + ACCOUNT_RESERVED_LEN;
+}
+
+#[cfg(feature = "light")]
+impl Default for AchievementReceipt {
+    fn default() -> Self {
+        Self {
+            achievement_id: String::new(),
+            recipient: Pubkey::default(),
+            asset: Pubkey::default(),
+            awarded_at: 0,
+            bump: 0,
+            version: 0,
+            reserved: [0; ACCOUNT_RESERVED_LEN],
+        }
+    }
+}
+
+#[account]
+pub struct AttestationNonces {
+    pub learner: Pubkey,
+    pub base_nonce: u64,
+    pub bitmap: [u64; 4],
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl AttestationNonces {
+    pub const LEN: usize = 32 + 8 + 32 + 1 + 1 + ACCOUNT_RESERVED_LEN;
+
+    // Sliding window over the last `ATTESTATION_NONCE_WINDOW` nonces. Anything
+    // below the window is treated as already used.
+    pub fn consume(&mut self, nonce: u64) -> Result<()> {
+        require!(
+            nonce >= self.base_nonce,
+            crate::errors::AcademyError::NonceAlreadyUsed
+        );
+
+        let offset = nonce - self.base_nonce;
+        if offset >= ATTESTATION_NONCE_WINDOW {
+            let new_base = nonce - (ATTESTATION_NONCE_WINDOW - 1);
+            self.shift_window(new_base - self.base_nonce);
+            self.base_nonce = new_base;
+        }
+
+        let bit = nonce - self.base_nonce;
+        let word_index = (bit / 64) as usize;
+        let mask = 1u64 << (bit % 64);
+        require!(
+            self.bitmap[word_index] & mask == 0,
+            crate::errors::AcademyError::NonceAlreadyUsed
+        );
+        self.bitmap[word_index] |= mask;
+        Ok(())
+    }
+
+    fn shift_window(&mut self, by: u64) {
+        if by >= ATTESTATION_NONCE_WINDOW {
+            self.bitmap = [0u64; 4];
+            return;
+        }
+        let word_shift = (by / 64) as usize;
+        let bit_shift = (by % 64) as u32;
+        let old = self.bitmap;
+        for i in 0..old.len() {
+            let src = i + word_shift;
+            let low = if src < old.len() { old[src] >> bit_shift } else { 0 };
+            let high = if bit_shift > 0 && src + 1 < old.len() {
+                old[src + 1] << (64 - bit_shift)
+            } else {
+                0
+            };
+            self.bitmap[i] = low | high;
+        }
+    }
+}
+
+// The season's XP mint is the leaderboard: its supply is the season total and
+// holder balances are the standings.
+#[account]
+pub struct Season {
+    pub season: u32,
+    pub mint: Pubkey,
+    pub started_at: i64,
+    pub closed_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl Season {
+    pub const LEN: usize = 4 + 32 + 8 + 8 + 1 + 1 + ACCOUNT_RESERVED_LEN;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LeaderboardEntry {
+    pub learner: Pubkey,
+    pub xp: u64,
+}
+
+impl LeaderboardEntry {
+    pub const LEN: usize = 32 + 8;
+}
+
+// Top `LEADERBOARD_SIZE` XP holders, highest first; only `entries[..count]` is
+// meaningful. Ties keep whoever got there first ahead.
+#[account]
+pub struct Leaderboard {
+    pub season: u32,
+    pub count: u8,
+    pub entries: [LeaderboardEntry; LEADERBOARD_SIZE],
+    pub updated_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl Leaderboard {
+    pub const LEN: usize =
+        4 + 1 + LEADERBOARD_SIZE * LeaderboardEntry::LEN + 8 + 1 + 1 + ACCOUNT_RESERVED_LEN;
+
+    // Sets `learner`'s total to `xp` if they are on the board or now outrank
+    // the last entry, then moves them to their place: up after a gain, down
+    // after a clawback.
+    pub fn record(&mut self, learner: Pubkey, xp: u64, now: i64) {
+        let count = self.count as usize;
+        let mut index = match self.entries[..count].iter().position(|e| e.learner == learner) {
+            Some(index) => index,
+            None if count < LEADERBOARD_SIZE => {
+                self.count += 1;
+                count
+            }
+            None if xp > self.entries[LEADERBOARD_SIZE - 1].xp => LEADERBOARD_SIZE - 1,
+            None => return,
+        };
+        self.entries[index] = LeaderboardEntry { learner, xp };
+        while index > 0 && self.entries[index - 1].xp < xp {
+            self.entries.swap(index - 1, index);
+            index -= 1;
+        }
+        while index + 1 < self.count as usize && self.entries[index + 1].xp >= xp {
+            self.entries.swap(index, index + 1);
+            index += 1;
+        }
+        self.updated_at = now;
+    }
+
+    // Lowers an on-board learner's total; anyone off the board stays off.
+    pub fn deduct(&mut self, learner: Pubkey, amount: u64, now: i64) {
+        if let Some(xp) = self.xp_of(&learner) {
+            self.record(learner, xp.saturating_sub(amount), now);
+        }
+    }
+
+    pub fn xp_of(&self, learner: &Pubkey) -> Option<u64> {
+        self.entries[..self.count as usize]
+            .iter()
+            .find(|e| e.learner == *learner)
+            .map(|e| e.xp)
+    }
+}
+
+// Season prize pool. Leaves are `(index, learner, amount)`; bit `index` of
+// `claimed` is set once that leaf has been paid.
+#[account]
+pub struct RewardDistribution {
+    pub season: u32,
+    pub mint: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+    pub num_recipients: u32,
+    pub claimed_count: u32,
+    pub claimed: [u64; (MAX_DISTRIBUTION_RECIPIENTS / 64) as usize],
+    pub expires_at: i64,
+    pub created_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl RewardDistribution {
+    pub const LEN: usize = 4
+        + 32
+        + 32
+        + 8
+        + 8
+        + 4
+        + 4
+        + (MAX_DISTRIBUTION_RECIPIENTS / 8) as usize
+        + 8
+        + 8
+        + 1
+        + 1
+        + ACCOUNT_RESERVED_LEN;
+
+    pub fn is_claimed(&self, index: u32) -> bool {
+        self.claimed[(index / 64) as usize] & (1u64 << (index % 64)) != 0
+    }
+
+    pub fn set_claimed(&mut self, index: u32) {
+        self.claimed[(index / 64) as usize] |= 1u64 << (index % 64);
+    }
+}
+
+#[account]
+pub struct Guild {
+    pub guild_id: String,
+    pub name: String,
+    pub leader: Pubkey,
+    pub member_cap: u32,
+    pub member_count: u32,
+    pub total_xp: u64,
+    pub created_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl Guild {
+    pub const LEN: usize = (4 + MAX_GUILD_ID_LEN)
+        + (4 + MAX_GUILD_NAME_LEN)
+        + 32
+        + 4
+        + 4
+        + 8
+        + 8
+        + 1
+        + 1
+        + ACCOUNT_RESERVED_LEN;
+
+    pub fn record_xp(&mut self, amount: u64) -> Result<()> {
+        self.total_xp = self
+            .total_xp
+            .checked_add(amount)
+            .ok_or_else(|| error!(crate::errors::AcademyError::Overflow))?;
+        Ok(())
+    }
+
+    pub fn revoke_xp(&mut self, amount: u64) {
+        self.total_xp = self.total_xp.saturating_sub(amount);
+    }
+}
+
+// One per learner, kept after leaving so the switch cooldown survives; `guild`
+// is the default pubkey while the learner is guildless.
+#[account]
+pub struct GuildMembership {
+    pub learner: Pubkey,
+    pub guild: Pubkey,
+    pub joined_at: i64,
+    pub left_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl GuildMembership {
+    pub const LEN: usize = 32 + 32 + 8 + 8 + 1 + 1 + ACCOUNT_RESERVED_LEN;
+
+    pub fn in_guild(&self) -> bool {
+        self.guild != Pubkey::default()
+    }
+
+    pub fn join(&mut self, guild: &mut Account<Guild>, now: i64) -> Result<()> {
+        require!(
+            !self.in_guild(),
+            crate::errors::AcademyError::AlreadyInGuild
+        );
+        require!(
+            self.left_at == 0 || now >= self.left_at.saturating_add(GUILD_SWITCH_COOLDOWN_SECS),
+            crate::errors::AcademyError::GuildSwitchCooldown
+        );
+        require!(
+            guild.member_count < guild.member_cap,
+            crate::errors::AcademyError::GuildFull
+        );
+        guild.member_count += 1;
+        self.guild = guild.key();
+        self.joined_at = now;
+        Ok(())
+    }
+
+    // The caller checks that `guild` is the one this membership points at. A
+    // leader may only leave last, and takes the leadership with them.
+    pub fn leave(&mut self, guild: &mut Guild, now: i64) -> Result<()> {
+        let is_leader = self.learner == guild.leader;
+        require!(
+            !is_leader || guild.member_count == 1,
+            crate::errors::AcademyError::GuildLeaderCannotLeave
+        );
+        guild.member_count = guild
+            .member_count
+            .checked_sub(1)
+            .ok_or_else(|| error!(crate::errors::AcademyError::Overflow))?;
+        if is_leader {
+            guild.leader = Pubkey::default();
+        }
+        self.guild = Pubkey::default();
+        self.left_at = now;
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RewardPayout {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+impl RewardPayout {
+    pub const LEN: usize = 32 + 8;
+}
+
+#[account]
+pub struct RewardItem {
+    pub reward_id: String,
+    pub metadata_uri: String,
+    pub price_xp: u64,
+    pub max_supply: u32,
+    pub redeemed_count: u32,
+    pub per_learner_limit: u32,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub is_active: bool,
+    pub payout: Option<RewardPayout>,
+    pub created_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl RewardItem {
+    pub const LEN: usize = (4 + MAX_REWARD_ID_LEN)
+        + (4 + MAX_METADATA_URI_LEN)
+        + 8
+        + 4
+        + 4
+        + 4
+        + 8
+        + 8
+        + 1
+        + (1 + RewardPayout::LEN)
+        + 8
+        + 1
+        + 1
+        + ACCOUNT_RESERVED_LEN;
+
+    pub fn is_available(&self, now: i64) -> bool {
+        self.is_active && now >= self.starts_at && now < self.ends_at
+    }
+}
+
+#[account]
+pub struct RedemptionTally {
+    pub reward_item: Pubkey,
+    pub learner: Pubkey,
+    pub count: u32,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl RedemptionTally {
+    pub const LEN: usize = 32 + 32 + 4 + 1 + 1 + ACCOUNT_RESERVED_LEN;
+}
+
+// Receipt the fulfilment team works from; one per redemption, indexed by the
+// learner's tally at the time of redeeming.
+#[account]
+pub struct Redemption {
+    pub reward_item: Pubkey,
+    pub learner: Pubkey,
+    pub index: u32,
+    pub price_xp: u64,
+    pub payout_amount: u64,
+    pub redeemed_at: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_LEN],
+}
+
+impl Redemption {
+    pub const LEN: usize = 32 + 32 + 4 + 8 + 8 + 8 + 1 + 1 + ACCOUNT_RESERVED_LEN;
+}
+
+// Layouts as first deployed, before `version`/`reserved` existed. Most have
+// since gained fields ahead of `bump`, so zero-extending them would shift
+// every later field; `migrate_account` recognises them by their exact size
+// and converts field by field instead. Values keep their version-0 encoding
+// and `Versioned::upgrade` rewrites them afterwards.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyConfigV0 {
+    pub authority: Pubkey,
+    pub backend_signer: Pubkey,
+    pub xp_mint: Pubkey,
+    pub bump: u8,
+}
+
+impl LegacyConfigV0 {
+    pub const LEN: usize = 32 + 32 + 32 + 1;
+}
+
+// `backend_signer` becomes a `BackendSignerRole`, which `migrate_account`
+// seeds alongside and counts once it exists.
+impl From<LegacyConfigV0> for Config {
+    fn from(legacy: LegacyConfigV0) -> Self {
+        Self {
+            authority: legacy.authority,
+            backend_signer_count: 0,
+            xp_mint: legacy.xp_mint,
+            treasury: legacy.authority,
+            submission_deposit: 0,
+            rejection_slash_bps: 0,
+            scholarship_pool: legacy.authority,
+            bump: legacy.bump,
+            version: 0,
+            current_season: 0,
+            season_mint: Pubkey::default(),
+            season_active: false,
+            reserved: [0; ACCOUNT_RESERVED_LEN - 37],
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyCourseV0 {
+    pub course_id: String,
+    pub creator: Pubkey,
+    pub content_tx_id: [u8; 32],
+    pub lesson_count: u8,
+    pub difficulty: u8,
+    pub xp_per_lesson: u32,
+    pub track_id: u32,
+    pub track_level: u32,
+    pub prerequisite: Option<Pubkey>,
+    pub creator_reward_xp: u32,
+    pub min_completions_for_reward: u32,
+    pub completion_count: u32,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl LegacyCourseV0 {
+    pub const LEN: usize =
+        (4 + MAX_COURSE_ID_LEN) + 32 + 32 + 1 + 1 + 4 + 4 + 4 + (1 + 32) + 4 + 4 + 4 + 1 + 8 + 1;
+}
+
+// Every course live before review existed was created by the authority.
+impl From<LegacyCourseV0> for Course {
+    fn from(legacy: LegacyCourseV0) -> Self {
+        Self {
+            course_id: legacy.course_id,
+            creator: legacy.creator,
+            content_tx_id: legacy.content_tx_id,
+            lesson_count: legacy.lesson_count,
+            difficulty: legacy.difficulty,
+            xp_per_lesson: legacy.xp_per_lesson,
+            track_id: legacy.track_id,
+            track_level: legacy.track_level,
+            prerequisite: legacy.prerequisite,
+            creator_reward_xp: legacy.creator_reward_xp,
+            min_completions_for_reward: legacy.min_completions_for_reward,
+            completion_count: legacy.completion_count,
+            is_active: legacy.is_active,
+            status: CourseStatus::Approved,
+            pricing: None,
+            commitment: None,
+            max_duration_secs: None,
+            allow_progress_restore: false,
+            pending_creator_revenue: 0,
+            pending_treasury_revenue: 0,
+            created_at: legacy.created_at,
+            bump: legacy.bump,
+            version: 0,
+            mentor_graded_lessons: [0; 4],
+            reserved: [0; ACCOUNT_RESERVED_LEN - 32],
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyEnrollmentV0 {
+    pub course_id: String,
+    pub learner: Pubkey,
+    pub lesson_flags: [u64; 4],
+    pub enrolled_at: i64,
+    pub completed_at: Option<i64>,
+    pub credential_asset: Option<Pubkey>,
+    pub bump: u8,
+}
+
+impl LegacyEnrollmentV0 {
+    pub const LEN: usize = (4 + MAX_COURSE_ID_LEN) + 32 + 32 + 8 + (1 + 8) + (1 + 32) + 1;
+}
+
+impl From<LegacyEnrollmentV0> for Enrollment {
+    fn from(legacy: LegacyEnrollmentV0) -> Self {
+        Self {
+            course_id: legacy.course_id,
+            learner: legacy.learner,
+            lesson_flags: legacy.lesson_flags,
+            enrolled_at: legacy.enrolled_at,
+            completed_at: legacy.completed_at,
+            credential_asset: legacy.credential_asset,
+            escrowed_amount: 0,
+            commitment_amount: 0,
+            commitment_deadline: 0,
+            expires_at: None,
+            cohort: None,
+            bump: legacy.bump,
+            version: 0,
+            reserved: [0; ACCOUNT_RESERVED_LEN],
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyMinterRoleV0 {
+    pub minter: Pubkey,
+    pub label: String,
+    pub max_xp_per_call: I80F48,
+    pub total_xp_minted: I80F48,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl LegacyMinterRoleV0 {
+    pub const LEN: usize = 32 + (4 + MAX_MINTER_LABEL_LEN) + 16 + 16 + 1 + 8 + 1;
+}
+
+// No rolling window existed; a zero `window_secs` keeps it disabled.
+impl From<LegacyMinterRoleV0> for MinterRole {
+    fn from(legacy: LegacyMinterRoleV0) -> Self {
+        Self {
+            minter: legacy.minter,
+            label: legacy.label,
+            max_xp_per_call: legacy.max_xp_per_call,
+            total_xp_minted: legacy.total_xp_minted,
+            max_xp_per_window: I80F48::ZERO,
+            window_secs: 0,
+            window_start: 0,
+            window_minted: I80F48::ZERO,
+            is_active: legacy.is_active,
+            created_at: legacy.created_at,
+            bump: legacy.bump,
+            version: 0,
+            reserved: [0; ACCOUNT_RESERVED_LEN],
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyAchievementTypeV0 {
+    pub achievement_id: String,
+    pub name: String,
+    pub metadata_uri: String,
+    pub collection: Pubkey,
+    pub current_supply: u32,
+    pub max_supply: u32,
+    pub xp_reward: u32,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl LegacyAchievementTypeV0 {
+    pub const LEN: usize = (4 + MAX_ACHIEVEMENT_ID_LEN)
+        + (4 + MAX_ACHIEVEMENT_NAME_LEN)
+        + (4 + MAX_METADATA_URI_LEN)
+        + 32
+        + 4
+        + 4
+        + 4
+        + 1
+        + 8
+        + 1;
+}
+
+impl From<LegacyAchievementTypeV0> for AchievementType {
+    fn from(legacy: LegacyAchievementTypeV0) -> Self {
+        Self {
+            achievement_id: legacy.achievement_id,
+            name: legacy.name,
+            metadata_uri: legacy.metadata_uri,
+            collection: legacy.collection,
+            current_supply: legacy.current_supply,
+            max_supply: legacy.max_supply,
+            xp_reward: legacy.xp_reward,
+            is_active: legacy.is_active,
+            created_at: legacy.created_at,
+            bump: legacy.bump,
+            version: 0,
+            reserved: [0; ACCOUNT_RESERVED_LEN],
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyAchievementReceiptV0 {
+    pub achievement_id: String,
+    pub recipient: Pubkey,
+    pub asset: Pubkey,
+    pub awarded_at: i64,
+    pub bump: u8,
+}
+
+impl LegacyAchievementReceiptV0 {
     pub const LEN: usize = (4 + MAX_ACHIEVEMENT_ID_LEN) + 32 + 32 + 8 + 1;
 }
+
+impl From<LegacyAchievementReceiptV0> for AchievementReceipt {
+    fn from(legacy: LegacyAchievementReceiptV0) -> Self {
+        Self {
+            achievement_id: legacy.achievement_id,
+            recipient: legacy.recipient,
+            asset: legacy.asset,
+            awarded_at: legacy.awarded_at,
+            bump: legacy.bump,
+            version: 0,
+            reserved: [0; ACCOUNT_RESERVED_LEN],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `(a * b) >> 48` on 32-bit limbs, as an independent reference.
+    fn reference_mul_shr(a: u128, b: u128) -> Option<u128> {
+        let limbs = |x: u128| {
+            [
+                x as u32,
+                (x >> 32) as u32,
+                (x >> 64) as u32,
+                (x >> 96) as u32,
+            ]
+        };
+        let (a, b) = (limbs(a), limbs(b));
+        let mut product = [0u64; 9];
+        for i in 0..4 {
+            let mut carry = 0u64;
+            for j in 0..4 {
+                let t = product[i + j] + u64::from(a[i]) * u64::from(b[j]) + carry;
+                product[i + j] = t & u64::from(u32::MAX);
+                carry = t >> 32;
+            }
+            product[i + 4] += carry;
+        }
+        let mut wide = [0u128; 2];
+        for (k, limb) in product[..8].iter().enumerate() {
+            wide[k / 4] |= u128::from(*limb) << (32 * (k % 4));
+        }
+        if wide[1] >> I80F48::FRAC_BITS != 0 {
+            return None;
+        }
+        Some((wide[1] << (128 - I80F48::FRAC_BITS)) | (wide[0] >> I80F48::FRAC_BITS))
+    }
+
+    fn samples() -> Vec<u128> {
+        let mut state = 0x9e37_79b9_7f4a_7c15_f39c_c060_5ced_c834u128;
+        let mut samples = vec![
+            0,
+            1,
+            u64::MAX as u128,
+            1 << 64,
+            (1 << 64) + 1,
+            u128::MAX >> 1,
+            1 << 127,
+        ];
+        for shift in [0, 16, 40, 64, 80, 100] {
+            state = state
+                .wrapping_mul(0x2360_ed05_1fc6_5da4_4385_df64_9fcc_f645)
+                .wrapping_add(0x5851_f42d_4c95_7f2d_1405_7b7e_f767_814f);
+            samples.push(state >> shift);
+        }
+        samples
+    }
+
+    #[test]
+    fn mul_shr_frac_matches_reference() {
+        for &a in &samples() {
+            for &b in &samples() {
+                assert_eq!(mul_shr_frac(a, b), reference_mul_shr(a, b), "{a} * {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn mul_shr_frac_carries_between_halves() {
+        // Both cross terms fill the middle word, so adding them carries into
+        // bit 128, and the low word carries into the high one as well.
+        let a = u128::MAX >> 80;
+        let b = (u64::MAX as u128) << 16 | u64::MAX as u128;
+        assert_eq!(mul_shr_frac(a, b), reference_mul_shr(a, b));
+        let a = (1u128 << 64) | u64::MAX as u128;
+        assert_eq!(mul_shr_frac(a, a), reference_mul_shr(a, a));
+        assert_eq!(mul_shr_frac(u128::MAX, 1 << 48), Some(u128::MAX));
+        assert_eq!(mul_shr_frac(u128::MAX, 1 << 49), None);
+    }
+
+    #[test]
+    fn shl_frac_div_keeps_remainders() {
+        for &a in &samples() {
+            for &b in &samples() {
+                if b == 0 || a >> 80 != 0 {
+                    continue;
+                }
+                assert_eq!(shl_frac_div(a, b), Some((a << 48) / b), "{a} / {b}");
+            }
+        }
+        // 1/3 truncates; 7/2 is exact.
+        assert_eq!(shl_frac_div(1, 3), Some((1 << 48) / 3));
+        assert_eq!(shl_frac_div(7, 2), Some(7 << 47));
+        // The whole part may fill all 80 integer bits but no more; the fraction
+        // comes from the remainder.
+        assert_eq!(shl_frac_div((1 << 80) - 1, 1), Some(((1 << 80) - 1) << 48));
+        assert_eq!(shl_frac_div(1 << 80, 1), None);
+        assert_eq!(shl_frac_div((1 << 79) + 1, 2), Some((1 << 126) + (1 << 47)));
+        assert_eq!(shl_frac_div(u128::MAX, u128::MAX), Some(1 << 48));
+        assert_eq!(shl_frac_div(u128::MAX - 1, u128::MAX), Some((1 << 48) - 1));
+    }
+
+    #[test]
+    fn signed_products_and_quotients_truncate_toward_zero() {
+        let neg = |v: u64| I80F48 {
+            value: -I80F48::from_u64(v).value,
+        };
+        let three_halves = I80F48::from_ratio(3, 2).unwrap();
+        assert_eq!(neg(3).checked_mul(I80F48::from_u64(2)).unwrap(), neg(6));
+        assert_eq!(neg(2).checked_mul(neg(3)).unwrap(), I80F48::from_u64(6));
+        assert_eq!(neg(1).checked_mul(three_halves).unwrap().value, -(3 << 47));
+        assert_eq!(
+            neg(7).checked_div(I80F48::from_u64(2)).unwrap().value,
+            -(7 << 47)
+        );
+        assert_eq!(
+            neg(1).checked_div(I80F48::from_u64(3)).unwrap().value,
+            -((1 << 48) / 3)
+        );
+        assert_eq!(neg(1).checked_div(neg(3)).unwrap().value, (1 << 48) / 3);
+        let tiny = I80F48 { value: -1 };
+        assert_eq!(tiny.checked_mul(three_halves).unwrap().value, -1);
+        assert_eq!(tiny.checked_div(I80F48::from_u64(2)).unwrap().value, 0);
+    }
+
+    #[test]
+    fn min_and_max_bounds() {
+        let min = I80F48 { value: i128::MIN };
+        let two = I80F48::from_u64(2);
+        assert_eq!(I80F48::MAX.checked_mul(I80F48::ONE).unwrap(), I80F48::MAX);
+        assert_eq!(I80F48::MAX.checked_div(I80F48::ONE).unwrap(), I80F48::MAX);
+        assert_eq!(min.checked_mul(I80F48::ONE).unwrap(), min);
+        assert_eq!(min.checked_div(I80F48::ONE).unwrap(), min);
+        assert_eq!(I80F48::MAX.checked_div(two).unwrap().value, i128::MAX >> 1);
+        assert!(I80F48::MAX.checked_mul(two).is_err());
+        assert!(min.checked_mul(two).is_err());
+        // `-MIN` does not fit.
+        let minus_one = I80F48 {
+            value: -I80F48::ONE.value,
+        };
+        assert!(min.checked_mul(minus_one).is_err());
+        assert!(min.checked_div(minus_one).is_err());
+        assert!(I80F48::ONE.checked_div(I80F48::ZERO).is_err());
+        assert!(I80F48::MAX.checked_add(I80F48 { value: 1 }).is_err());
+        assert!(min.checked_sub(I80F48 { value: 1 }).is_err());
+    }
+
+    fn enrollment(lesson_flags: [u64; 4], completed_at: Option<i64>) -> Enrollment {
+        Enrollment {
+            course_id: "rust-101".to_string(),
+            learner: Pubkey::new_unique(),
+            lesson_flags,
+            enrolled_at: 1_000,
+            completed_at,
+            credential_asset: None,
+            escrowed_amount: 0,
+            commitment_amount: 0,
+            commitment_deadline: 0,
+            expires_at: None,
+            cohort: None,
+            bump: 255,
+            version: CURRENT_ACCOUNT_VERSION,
+            reserved: [0; ACCOUNT_RESERVED_LEN],
+        }
+    }
+
+    // What `init_if_needed` hands `record_attempt` on a learner's first close.
+    fn empty_archive() -> EnrollmentArchive {
+        EnrollmentArchive {
+            course_id: String::new(),
+            learner: Pubkey::default(),
+            attempt_count: 0,
+            best_lesson_flags: [0; 4],
+            first_enrolled_at: 0,
+            last_enrolled_at: 0,
+            last_closed_at: 0,
+            completed_at: None,
+            bump: 0,
+            version: 0,
+            reserved: [0; ACCOUNT_RESERVED_LEN],
+        }
+    }
+
+    fn account_data<T: AccountSerialize>(account: &T) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn first_enrollment_starts_empty() {
+        assert_eq!(
+            EnrollmentArchive::restored_lesson_flags(&[], true).unwrap(),
+            [0; 4]
+        );
+    }
+
+    #[test]
+    fn reenrolling_after_closing_a_finalized_enrollment_is_rejected() {
+        // finalize_course leaves every lesson done and `completed_at` set.
+        let finalized = enrollment([0b111, 0, 0, 0], Some(2_000));
+        let mut archive = empty_archive();
+        // close_enrollment has no cooldown for completed enrollments.
+        archive.record_attempt(&finalized, 254, 2_500).unwrap();
+        let data = account_data(&archive);
+
+        // Without this the learner could enroll fresh and finalize again.
+        for allow_progress_restore in [true, false] {
+            assert_eq!(
+                EnrollmentArchive::restored_lesson_flags(&data, allow_progress_restore)
+                    .unwrap_err(),
+                error!(crate::errors::AcademyError::ArchiveAlreadyCompleted)
+            );
+        }
+
+        // A later unfinished attempt does not clear the completion.
+        archive
+            .record_attempt(&enrollment([0b1, 0, 0, 0], None), 254, 3_000)
+            .unwrap();
+        assert!(EnrollmentArchive::restored_lesson_flags(&account_data(&archive), true).is_err());
+    }
+
+    #[test]
+    fn unfinished_attempts_restore_best_progress_when_allowed() {
+        let mut archive = empty_archive();
+        archive
+            .record_attempt(&enrollment([0b011, 0, 0, 0], None), 254, 2_000)
+            .unwrap();
+        archive
+            .record_attempt(&enrollment([0b100, 0, 0, 0], None), 254, 3_000)
+            .unwrap();
+        assert_eq!(archive.attempt_count, 2);
+        let data = account_data(&archive);
+
+        assert_eq!(
+            EnrollmentArchive::restored_lesson_flags(&data, true).unwrap(),
+            [0b011, 0, 0, 0]
+        );
+        assert_eq!(
+            EnrollmentArchive::restored_lesson_flags(&data, false).unwrap(),
+            [0; 4]
+        );
+    }
+
+    #[test]
+    fn closing_frees_a_seat_without_undercounting_enrollments() {
+        let mut cohort = Cohort {
+            course: Pubkey::new_unique(),
+            cohort_id: 1,
+            starts_at: 0,
+            ends_at: 10_000,
+            capacity: 2,
+            enrolled_count: 0,
+            completed_count: 0,
+            waitlist_enabled: true,
+            waitlist_count: 0,
+            created_at: 0,
+            bump: 255,
+            version: CURRENT_ACCOUNT_VERSION,
+            occupied_seats: 0,
+            reserved: [0; ACCOUNT_RESERVED_LEN - 4],
+        };
+        cohort.take_seat().unwrap();
+        cohort.take_seat().unwrap();
+        assert!(cohort.is_full());
+
+        cohort.release_seat();
+        assert!(!cohort.is_full());
+        cohort.take_seat().unwrap();
+        assert!(cohort.is_full());
+        assert_eq!(cohort.enrolled_count, 3);
+        assert_eq!(cohort.occupied_seats, 2);
+    }
+
+    fn leaderboard() -> Leaderboard {
+        Leaderboard {
+            season: LIFETIME_LEADERBOARD,
+            count: 0,
+            entries: [LeaderboardEntry::default(); LEADERBOARD_SIZE],
+            updated_at: 0,
+            bump: 255,
+            version: CURRENT_ACCOUNT_VERSION,
+            reserved: [0; ACCOUNT_RESERVED_LEN],
+        }
+    }
+
+    fn ranking(board: &Leaderboard) -> Vec<(Pubkey, u64)> {
+        board.entries[..board.count as usize]
+            .iter()
+            .map(|e| (e.learner, e.xp))
+            .collect()
+    }
+
+    #[test]
+    fn leaderboard_inserts_in_xp_order() {
+        let mut board = leaderboard();
+        let (a, b, c) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        board.record(a, 50, 1);
+        board.record(b, 80, 2);
+        board.record(c, 10, 3);
+        assert_eq!(ranking(&board), vec![(b, 80), (a, 50), (c, 10)]);
+        assert_eq!(board.updated_at, 3);
+
+        // Re-recording moves an existing entry rather than adding one.
+        board.record(c, 60, 4);
+        assert_eq!(ranking(&board), vec![(b, 80), (c, 60), (a, 50)]);
+    }
+
+    #[test]
+    fn full_leaderboard_evicts_only_for_a_higher_total() {
+        let mut board = leaderboard();
+        let learners: Vec<Pubkey> = (0..LEADERBOARD_SIZE)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        for (i, learner) in learners.iter().enumerate() {
+            board.record(*learner, 100 + i as u64, 1);
+        }
+        assert_eq!(board.count as usize, LEADERBOARD_SIZE);
+        let last = learners[0];
+        assert_eq!(board.entries[LEADERBOARD_SIZE - 1].learner, last);
+
+        // Matching the last entry is not enough to displace it.
+        let tied = Pubkey::new_unique();
+        board.record(tied, 100, 2);
+        assert_eq!(board.xp_of(&tied), None);
+        assert_eq!(board.entries[LEADERBOARD_SIZE - 1].learner, last);
+        assert_eq!(board.updated_at, 1);
+
+        let newcomer = Pubkey::new_unique();
+        board.record(newcomer, 1_000, 3);
+        assert_eq!(board.count as usize, LEADERBOARD_SIZE);
+        assert_eq!(
+            board.entries[0],
+            LeaderboardEntry {
+                learner: newcomer,
+                xp: 1_000
+            }
+        );
+        assert_eq!(board.xp_of(&last), None);
+    }
+
+    #[test]
+    fn leaderboard_ties_keep_the_earlier_learner_ahead() {
+        let mut board = leaderboard();
+        let (first, second, third) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        board.record(first, 70, 1);
+        board.record(second, 70, 2);
+        assert_eq!(ranking(&board), vec![(first, 70), (second, 70)]);
+
+        // Catching up from below does not overtake.
+        board.record(third, 40, 3);
+        board.record(third, 70, 4);
+        assert_eq!(
+            ranking(&board),
+            vec![(first, 70), (second, 70), (third, 70)]
+        );
+    }
+
+    #[test]
+    fn leaderboard_clawback_moves_an_entry_down() {
+        let mut board = leaderboard();
+        let (a, b, c) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        board.record(a, 90, 1);
+        board.record(b, 60, 2);
+        board.record(c, 30, 3);
+
+        board.deduct(a, 65, 4);
+        assert_eq!(ranking(&board), vec![(b, 60), (c, 30), (a, 25)]);
+
+        // Dropping to a tie puts the learner behind whoever held it first.
+        board.deduct(b, 30, 5);
+        assert_eq!(ranking(&board), vec![(c, 30), (b, 30), (a, 25)]);
+
+        // Deductions saturate, and learners off the board stay off.
+        board.deduct(a, 1_000, 6);
+        assert_eq!(board.xp_of(&a), Some(0));
+        let outsider = Pubkey::new_unique();
+        board.deduct(outsider, 10, 7);
+        assert_eq!(board.xp_of(&outsider), None);
+        assert_eq!(board.count, 3);
+    }
+
+    fn nonces() -> AttestationNonces {
+        AttestationNonces {
+            learner: Pubkey::new_unique(),
+            base_nonce: 0,
+            bitmap: [0; 4],
+            bump: 255,
+            version: CURRENT_ACCOUNT_VERSION,
+            reserved: [0; ACCOUNT_RESERVED_LEN],
+        }
+    }
+
+    fn nonce_used() -> Error {
+        error!(crate::errors::AcademyError::NonceAlreadyUsed)
+    }
+
+    #[test]
+    fn attestation_nonce_replay_is_rejected() {
+        let mut window = nonces();
+        window.consume(7).unwrap();
+        assert_eq!(window.consume(7).unwrap_err(), nonce_used());
+
+        // Nonces need not arrive in order while they stay inside the window.
+        window.consume(3).unwrap();
+        window.consume(ATTESTATION_NONCE_WINDOW - 1).unwrap();
+        assert_eq!(window.base_nonce, 0);
+        assert_eq!(window.consume(3).unwrap_err(), nonce_used());
+    }
+
+    #[test]
+    fn attestation_nonce_window_slides_and_keeps_used_bits() {
+        let mut window = nonces();
+        window.consume(100).unwrap();
+        window.consume(200).unwrap();
+
+        // Past the top of the window: the base moves so the new nonce is last.
+        let next = ATTESTATION_NONCE_WINDOW + 99;
+        window.consume(next).unwrap();
+        assert_eq!(window.base_nonce, next - (ATTESTATION_NONCE_WINDOW - 1));
+        assert_eq!(window.base_nonce, 100);
+
+        // Bits that survived the shift still block replays.
+        assert_eq!(window.consume(100).unwrap_err(), nonce_used());
+        assert_eq!(window.consume(200).unwrap_err(), nonce_used());
+        assert_eq!(window.consume(next).unwrap_err(), nonce_used());
+        window.consume(150).unwrap();
+
+        // A jump of more than a full window clears everything.
+        let far = next + 10 * ATTESTATION_NONCE_WINDOW;
+        window.consume(far).unwrap();
+        assert_eq!(window.bitmap.iter().map(|w| w.count_ones()).sum::<u32>(), 1);
+        window.consume(far - 1).unwrap();
+    }
+
+    #[test]
+    fn attestation_nonce_below_the_window_is_rejected() {
+        let mut window = nonces();
+        window.consume(1_000).unwrap();
+        assert_eq!(window.base_nonce, 1_000 - (ATTESTATION_NONCE_WINDOW - 1));
+
+        // Never consumed, but too old to tell apart from a replay.
+        assert_eq!(
+            window.consume(window.base_nonce - 1).unwrap_err(),
+            nonce_used()
+        );
+        assert_eq!(window.consume(0).unwrap_err(), nonce_used());
+        window.consume(window.base_nonce).unwrap();
+    }
+
+    fn split(recipient: Pubkey, bps: u16) -> CreatorSplit {
+        CreatorSplit { recipient, bps }
+    }
+
+    #[test]
+    fn removed_co_creator_keeps_unclaimed_rewards() {
+        let (creator, co_creator, newcomer) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut vault = CreatorVault::new(Pubkey::new_unique(), creator, 255);
+        vault
+            .set_splits(&[split(creator, 7_000), split(co_creator, 3_000)])
+            .unwrap();
+        vault.accrue(1_000).unwrap();
+
+        vault
+            .set_splits(&[split(newcomer, 5_000), split(creator, 5_000)])
+            .unwrap();
+        let shares: Vec<_> = vault
+            .shares
+            .iter()
+            .map(|s| (s.recipient, s.bps, s.pending_xp))
+            .collect();
+        assert_eq!(
+            shares,
+            vec![
+                (newcomer, 5_000, 0),
+                (creator, 5_000, 700),
+                (co_creator, 0, 300)
+            ]
+        );
+
+        // The removed co-creator earns nothing more; dust goes to the new primary.
+        vault.accrue(101).unwrap();
+        assert_eq!(vault.shares[0].pending_xp, 51);
+        assert_eq!(vault.shares[1].pending_xp, 750);
+        assert_eq!(vault.shares[2].pending_xp, 300);
+
+        // Once paid out there is nothing to keep.
+        vault.shares[2].pending_xp = 0;
+        vault.set_splits(&[split(creator, 10_000)]).unwrap();
+        let recipients: Vec<_> = vault.shares.iter().map(|s| (s.recipient, s.bps)).collect();
+        assert_eq!(recipients, vec![(creator, 10_000), (newcomer, 0)]);
+    }
+
+    #[test]
+    fn set_splits_is_capped_by_vault_capacity() {
+        let creator = Pubkey::new_unique();
+        let mut vault = CreatorVault::new(Pubkey::new_unique(), creator, 255);
+        let co_creators: Vec<_> = (0..MAX_CO_CREATORS)
+            .map(|_| {
+                split(
+                    Pubkey::new_unique(),
+                    BPS_DENOMINATOR / MAX_CO_CREATORS as u16,
+                )
+            })
+            .collect();
+        vault.set_splits(&co_creators).unwrap();
+        vault.accrue(8_000).unwrap();
+
+        assert_eq!(
+            vault
+                .set_splits(&[split(creator, BPS_DENOMINATOR)])
+                .unwrap_err(),
+            error!(crate::errors::AcademyError::CreatorRewardPending)
+        );
+        assert_eq!(vault.shares.len(), MAX_CO_CREATORS);
+    }
+
+    fn guild(leader: Pubkey, member_count: u32) -> Guild {
+        Guild {
+            guild_id: "guild".to_string(),
+            name: "Guild".to_string(),
+            leader,
+            member_cap: 10,
+            member_count,
+            total_xp: 0,
+            created_at: 0,
+            bump: 255,
+            version: CURRENT_ACCOUNT_VERSION,
+            reserved: [0; ACCOUNT_RESERVED_LEN],
+        }
+    }
+
+    fn membership(learner: Pubkey) -> GuildMembership {
+        GuildMembership {
+            learner,
+            guild: Pubkey::new_unique(),
+            joined_at: 0,
+            left_at: 0,
+            bump: 255,
+            version: CURRENT_ACCOUNT_VERSION,
+            reserved: [0; ACCOUNT_RESERVED_LEN],
+        }
+    }
+
+    #[test]
+    fn guild_leader_leaves_last_and_clears_leadership() {
+        let (leader, member) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut guild = guild(leader, 2);
+        let mut leader_membership = membership(leader);
+
+        assert_eq!(
+            leader_membership.leave(&mut guild, 10).unwrap_err(),
+            error!(crate::errors::AcademyError::GuildLeaderCannotLeave)
+        );
+        membership(member).leave(&mut guild, 10).unwrap();
+        assert_eq!((guild.member_count, guild.leader), (1, leader));
+
+        leader_membership.leave(&mut guild, 20).unwrap();
+        assert_eq!(guild.member_count, 0);
+        assert_eq!(guild.leader, Pubkey::default());
+        assert!(!leader_membership.in_guild());
+        assert_eq!(leader_membership.left_at, 20);
+    }
+
+    #[test]
+    fn leaving_an_empty_guild_is_rejected() {
+        let mut guild = guild(Pubkey::default(), 0);
+        assert_eq!(
+            membership(Pubkey::new_unique())
+                .leave(&mut guild, 10)
+                .unwrap_err(),
+            error!(crate::errors::AcademyError::Overflow)
+        );
+    }
+}
@@ -1,12 +1,162 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{self, Mint, MintTo, TokenAccount, TokenInterface};
+use anchor_lang::solana_program::{
+    ed25519_program,
+    hash::{hash, hashv},
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::token_2022::spl_token_2022::{extension::ExtensionType, state::Mint as MintState};
+use anchor_spl::token_2022::{self, InitializeMint2};
+use anchor_spl::token_interface::{
+    self, metadata_pointer_initialize, non_transferable_mint_initialize,
+    permanent_delegate_initialize, spl_pod::optional_keys::OptionalNonZeroPubkey,
+    spl_token_metadata_interface::state::TokenMetadata, token_metadata_initialize,
+    MetadataPointerInitialize, Mint, MintTo, NonTransferableMintInitialize,
+    PermanentDelegateInitialize, TokenAccount, TokenInterface, TokenMetadataInitialize,
+    TransferChecked,
+};
 
-use crate::state::{Config, I80F48};
+use crate::{
+    errors::AcademyError,
+    state::{Cohort, Config, Course, Enrollment, Guild, GuildMembership, Leaderboard, I80F48},
+};
 
+pub const ATTESTATION_DOMAIN: &[u8] = b"academy:attestation:v2";
+pub const LESSONS_ATTESTATION_DOMAIN: &[u8] = b"academy:attestation:lessons:v2";
+
+// Course and achievement ids can be up to 64 bytes but a single PDA seed is
+// capped at 32, so PDAs are derived from the sha256 of the id instead.
+pub fn id_seed(id: &str) -> [u8; 32] {
+    hash(id.as_bytes()).to_bytes()
+}
+
+// Ids are lowercase slugs: `a-z`, `0-9`, `-` and `_`.
+pub fn is_valid_slug(id: &str) -> bool {
+    !id.is_empty()
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-' || b == b'_')
+}
+
+pub struct XpMintMetadata<'a> {
+    pub name: &'a str,
+    pub symbol: &'a str,
+    pub uri: &'a str,
+}
+
+// XP is a reputation score, not a tradable token: the mint is Token-2022 with
+// NonTransferable, and carries its own name/symbol/uri via MetadataPointer +
+// TokenMetadata. Config is the PermanentDelegate so farmed XP can be clawed
+// back. Anchor's `mint::` constraints can't express NonTransferable, so the
+// mint is assembled by hand. Used for the lifetime mint and every season mint.
+pub fn create_xp_mint<'info>(
+    xp_mint: &AccountInfo<'info>,
+    config: &AccountInfo<'info>,
+    config_bump: u8,
+    payer: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    metadata: XpMintMetadata,
+) -> Result<()> {
+    let mint_space = ExtensionType::try_calculate_account_len::<MintState>(&[
+        ExtensionType::NonTransferable,
+        ExtensionType::PermanentDelegate,
+        ExtensionType::MetadataPointer,
+    ])?;
+    let metadata_space = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(config.key()))?,
+        mint: xp_mint.key(),
+        name: metadata.name.to_string(),
+        symbol: metadata.symbol.to_string(),
+        uri: metadata.uri.to_string(),
+        additional_metadata: vec![],
+    }
+    .tlv_size_of()?;
+
+    // The token program reallocs the mint when writing metadata, so it is
+    // funded for its final size up front.
+    system_program::create_account(
+        CpiContext::new(
+            system_program.clone(),
+            CreateAccount {
+                from: payer.clone(),
+                to: xp_mint.clone(),
+            },
+        ),
+        Rent::get()?.minimum_balance(mint_space + metadata_space),
+        mint_space as u64,
+        token_program.key,
+    )?;
+
+    non_transferable_mint_initialize(CpiContext::new(
+        token_program.clone(),
+        NonTransferableMintInitialize {
+            token_program_id: token_program.clone(),
+            mint: xp_mint.clone(),
+        },
+    ))?;
+    permanent_delegate_initialize(
+        CpiContext::new(
+            token_program.clone(),
+            PermanentDelegateInitialize {
+                token_program_id: token_program.clone(),
+                mint: xp_mint.clone(),
+            },
+        ),
+        config.key,
+    )?;
+    metadata_pointer_initialize(
+        CpiContext::new(
+            token_program.clone(),
+            MetadataPointerInitialize {
+                token_program_id: token_program.clone(),
+                mint: xp_mint.clone(),
+            },
+        ),
+        Some(config.key()),
+        Some(xp_mint.key()),
+    )?;
+    token_2022::initialize_mint2(
+        CpiContext::new(
+            token_program.clone(),
+            InitializeMint2 {
+                mint: xp_mint.clone(),
+            },
+        ),
+        0,
+        config.key,
+        Some(config.key),
+    )?;
+
+    let config_seeds: &[&[u8]] = &[b"config", &[config_bump]];
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TokenMetadataInitialize {
+                token_program_id: token_program.clone(),
+                mint: xp_mint.clone(),
+                metadata: xp_mint.clone(),
+                mint_authority: config.clone(),
+                update_authority: config.clone(),
+            },
+            &[config_seeds],
+        ),
+        metadata.name.to_string(),
+        metadata.symbol.to_string(),
+        metadata.uri.to_string(),
+    )?;
+
+    Ok(())
+}
+
+// Every award lands on the lifetime mint and, while a season is running, on the
+// season mint as well. `season` is the season mint and the recipient's account
+// for it.
 pub fn mint_xp<'info>(
     config: &Account<'info, Config>,
     xp_mint: &InterfaceAccount<'info, Mint>,
     recipient_token_account: &InterfaceAccount<'info, TokenAccount>,
+    season: Option<(&InterfaceAccount<'info, Mint>, &InterfaceAccount<'info, TokenAccount>)>,
     token_program: &Interface<'info, TokenInterface>,
     amount: u64,
 ) -> Result<()> {
@@ -24,9 +174,308 @@ pub fn mint_xp<'info>(
             signer,
         ),
         amount,
+    )?;
+
+    if !config.season_active {
+        return Ok(());
+    }
+    let (season_mint, season_token_account) =
+        season.ok_or_else(|| error!(AcademyError::MissingSeasonAccounts))?;
+    require_keys_eq!(season_mint.key(), config.season_mint, AcademyError::MintMismatch);
+    require_keys_eq!(
+        season_token_account.mint,
+        config.season_mint,
+        AcademyError::MintMismatch
+    );
+    require_keys_eq!(
+        season_token_account.owner,
+        recipient_token_account.owner,
+        AcademyError::InvalidTokenAccount
+    );
+
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: season_mint.to_account_info(),
+                to: season_token_account.to_account_info(),
+                authority: config.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )
+}
+
+// Called after `mint_xp` with the recipient's token accounts as loaded, i.e.
+// their balances from before the mint.
+pub fn record_leaderboards<'info>(
+    config: &Account<'info, Config>,
+    leaderboard: &mut Account<'info, Leaderboard>,
+    season_leaderboard: Option<&mut Account<'info, Leaderboard>>,
+    recipient_token_account: &InterfaceAccount<'info, TokenAccount>,
+    season_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    amount: u64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let learner = recipient_token_account.owner;
+    // Both boards rank XP held, as of after this mint. Clawbacks and
+    // redemptions deduct what they burn, so an entry always matches the
+    // learner's balance.
+    let lifetime_xp = recipient_token_account
+        .amount
+        .checked_add(amount)
+        .ok_or_else(|| error!(AcademyError::Overflow))?;
+    leaderboard.record(learner, lifetime_xp, now);
+
+    if !config.season_active {
+        return Ok(());
+    }
+    let (Some(season_leaderboard), Some(season_token_account)) =
+        (season_leaderboard, season_token_account)
+    else {
+        return err!(AcademyError::MissingSeasonAccounts);
+    };
+    require!(
+        season_leaderboard.season == config.current_season,
+        AcademyError::LeaderboardMismatch
+    );
+    let season_xp = season_token_account
+        .amount
+        .checked_add(amount)
+        .ok_or_else(|| error!(AcademyError::Overflow))?;
+    season_leaderboard.record(learner, season_xp, now);
+    Ok(())
+}
+
+// `guild_membership` is the recipient's membership PDA, which need not exist.
+// A member's XP always counts towards their guild, so the guild must be passed
+// whenever the membership names one.
+pub fn record_guild_xp<'info>(
+    guild_membership: &AccountInfo<'info>,
+    guild: Option<&mut Account<'info, Guild>>,
+    amount: u64,
+) -> Result<()> {
+    match member_guild(guild_membership, guild)? {
+        Some(guild) => guild.record_xp(amount),
+        None => Ok(()),
+    }
+}
+
+// Clawed-back XP comes off the learner's current guild, which is where it
+// counts now even if it was earned elsewhere.
+pub fn revoke_guild_xp<'info>(
+    guild_membership: &AccountInfo<'info>,
+    guild: Option<&mut Account<'info, Guild>>,
+    amount: u64,
+) -> Result<()> {
+    if let Some(guild) = member_guild(guild_membership, guild)? {
+        guild.revoke_xp(amount);
+    }
+    Ok(())
+}
+
+fn member_guild<'a, 'info>(
+    guild_membership: &AccountInfo<'info>,
+    guild: Option<&'a mut Account<'info, Guild>>,
+) -> Result<Option<&'a mut Account<'info, Guild>>> {
+    if guild_membership.owner != &crate::ID || guild_membership.data_is_empty() {
+        return Ok(None);
+    }
+    let membership =
+        GuildMembership::try_deserialize(&mut &guild_membership.try_borrow_data()?[..])?;
+    if !membership.in_guild() {
+        return Ok(None);
+    }
+    let guild = guild.ok_or_else(|| error!(AcademyError::MissingGuildAccount))?;
+    require_keys_eq!(guild.key(), membership.guild, AcademyError::NotInGuild);
+    Ok(Some(guild))
+}
+
+pub fn transfer_from_vault<'info>(
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    vault_seeds: &[&[u8]],
+    amount: u64,
+) -> Result<()> {
+    let signer: &[&[&[u8]]] = &[vault_seeds];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: vault.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: vault.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+        mint.decimals,
+    )
+}
+
+// Direct lamport debit; `from` must be owned by this program.
+pub fn move_lamports(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+    **from.try_borrow_mut_lamports()? = from
+        .lamports()
+        .checked_sub(amount)
+        .ok_or_else(|| error!(AcademyError::Overflow))?;
+    **to.try_borrow_mut_lamports()? = to
+        .lamports()
+        .checked_add(amount)
+        .ok_or_else(|| error!(AcademyError::Overflow))?;
+    Ok(())
+}
+
+// Creates a program-owned PDA outside an `init` constraint, for accounts that
+// `migrate_account` backfills. Like `init`, it tolerates lamports already
+// sitting at the address.
+pub fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let signer: &[&[&[u8]]] = &[signer_seeds];
+    let rent = Rent::get()?.minimum_balance(space);
+    if account.lamports() == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                signer,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let rent_due = rent.saturating_sub(account.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: account.clone(),
+            },
+            signer,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: account.clone(),
+            },
+            signer,
+        ),
+        &crate::ID,
     )
 }
 
+// The SPL side of a commitment payout: the course commitment vault (with its
+// bump), its mint and token program. Only needed for SPL stakes.
+pub struct CommitmentVault<'a, 'info> {
+    pub vault: Option<(&'a InterfaceAccount<'info, TokenAccount>, u8)>,
+    pub mint: Option<&'a InterfaceAccount<'info, Mint>>,
+    pub token_program: Option<&'a Interface<'info, TokenInterface>>,
+}
+
+// Pays an enrollment's commitment stake to `destination`: straight from
+// `stake_holder` (the enrollment account) for SOL stakes, or out of the course
+// commitment vault into a token account owned by `destination` for SPL stakes.
+pub fn pay_out_commitment<'info>(
+    course: &Account<'info, Course>,
+    enrollment: &Enrollment,
+    stake_holder: Option<&AccountInfo<'info>>,
+    destination: &AccountInfo<'info>,
+    destination_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    commitment_vault: CommitmentVault<'_, 'info>,
+) -> Result<u64> {
+    let amount = enrollment.commitment_amount;
+    let policy = course
+        .commitment
+        .ok_or_else(|| error!(AcademyError::MissingCommitmentAccounts))?;
+
+    match policy.mint {
+        None => {
+            let stake_holder =
+                stake_holder.ok_or_else(|| error!(AcademyError::MissingCommitmentAccounts))?;
+            move_lamports(stake_holder, destination, amount)?
+        }
+        Some(mint) => {
+            let (
+                Some(destination_token_account),
+                Some((vault, vault_bump)),
+                Some(commitment_mint),
+                Some(token_program),
+            ) = (
+                destination_token_account,
+                commitment_vault.vault,
+                commitment_vault.mint,
+                commitment_vault.token_program,
+            )
+            else {
+                return err!(AcademyError::MissingCommitmentAccounts);
+            };
+            require_keys_eq!(commitment_mint.key(), mint, AcademyError::MintMismatch);
+            require_keys_eq!(
+                destination_token_account.owner,
+                destination.key(),
+                AcademyError::InvalidTokenAccount
+            );
+
+            let course_key = course.key();
+            transfer_from_vault(
+                vault,
+                commitment_mint,
+                destination_token_account,
+                token_program,
+                &[b"commitment_vault", course_key.as_ref(), &[vault_bump]],
+                amount,
+            )?;
+        }
+    }
+
+    Ok(amount)
+}
+
+// Frees the seat a closing enrollment held in its cohort. The cohort must be
+// passed whenever the enrollment belongs to one.
+pub fn release_cohort_seat(
+    enrollment: &Enrollment,
+    cohort: Option<&mut Account<Cohort>>,
+) -> Result<()> {
+    let Some(cohort_key) = enrollment.cohort else {
+        return Ok(());
+    };
+    let cohort = cohort.ok_or_else(|| error!(AcademyError::CohortMismatch))?;
+    require_keys_eq!(cohort.key(), cohort_key, AcademyError::CohortMismatch);
+    cohort.release_seat();
+    Ok(())
+}
+
 pub fn checked_add_i80(lhs: I80F48, rhs: I80F48) -> Result<I80F48> {
     lhs.checked_add(rhs)
 }
@@ -35,3 +484,202 @@ pub fn i80_to_u32(amount: I80F48) -> Result<u32> {
     let raw = amount.as_u64()?;
     u32::try_from(raw).map_err(|_| error!(crate::errors::AcademyError::InvalidAmount))
 }
+
+// Leaves and inner nodes are domain-separated so a node can't be replayed as a
+// leaf, and pairs are hashed in sorted order so proofs carry no directions.
+pub fn distribution_leaf(index: u32, learner: &Pubkey, amount: u64) -> [u8; 32] {
+    hashv(&[
+        &[0u8],
+        &index.to_le_bytes(),
+        learner.as_ref(),
+        &amount.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        let (left, right) = if node <= *sibling {
+            (&node, sibling)
+        } else {
+            (sibling, &node)
+        };
+        hashv(&[&[1u8], left, right]).to_bytes()
+    });
+    computed == *root
+}
+
+// Binds the program id and the deployment's XP mint (a fresh keypair per
+// `initialize`) so an attestation cannot be replayed against another
+// deployment or cluster.
+pub fn attestation_message(
+    config: &Config,
+    learner: &Pubkey,
+    course: &Pubkey,
+    lesson_index: u8,
+    nonce: u64,
+    expiry: i64,
+) -> Vec<u8> {
+    let mut message =
+        Vec::with_capacity(ATTESTATION_DOMAIN.len() + 32 + 32 + 32 + 32 + 1 + 8 + 8);
+    message.extend_from_slice(ATTESTATION_DOMAIN);
+    message.extend_from_slice(crate::ID.as_ref());
+    message.extend_from_slice(config.xp_mint.as_ref());
+    message.extend_from_slice(learner.as_ref());
+    message.extend_from_slice(course.as_ref());
+    message.push(lesson_index);
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message.extend_from_slice(&expiry.to_le_bytes());
+    message
+}
+
+// Batch form for `complete_lessons_attested`. Its own domain keeps a signed
+// lesson mask from ever parsing as a single-lesson attestation.
+pub fn lessons_attestation_message(
+    config: &Config,
+    learner: &Pubkey,
+    course: &Pubkey,
+    lesson_mask: &[u64; 4],
+    nonce: u64,
+    expiry: i64,
+) -> Vec<u8> {
+    let mut message =
+        Vec::with_capacity(LESSONS_ATTESTATION_DOMAIN.len() + 32 + 32 + 32 + 32 + 32 + 8 + 8);
+    message.extend_from_slice(LESSONS_ATTESTATION_DOMAIN);
+    message.extend_from_slice(crate::ID.as_ref());
+    message.extend_from_slice(config.xp_mint.as_ref());
+    message.extend_from_slice(learner.as_ref());
+    message.extend_from_slice(course.as_ref());
+    for word in lesson_mask {
+        message.extend_from_slice(&word.to_le_bytes());
+    }
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message.extend_from_slice(&expiry.to_le_bytes());
+    message
+}
+
+// Expects the instruction immediately before the current one to be a
+// single-signature Ed25519 program instruction whose key and message live in
+// its own data (instruction index u16::MAX).
+pub fn verify_backend_attestation(
+    instructions_sysvar: &AccountInfo,
+    backend_signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, AcademyError::InvalidAttestation);
+    let ed25519_ix =
+        load_instruction_at_checked(usize::from(current_index - 1), instructions_sysvar)?;
+    require_keys_eq!(
+        ed25519_ix.program_id,
+        ed25519_program::ID,
+        AcademyError::InvalidAttestation
+    );
+
+    let data = &ed25519_ix.data;
+    require!(data.len() >= 16 && data[0] == 1, AcademyError::InvalidAttestation);
+    let read_u16 = |at: usize| usize::from(u16::from_le_bytes([data[at], data[at + 1]]));
+    let signature_ix_index = read_u16(4);
+    let public_key_offset = read_u16(6);
+    let public_key_ix_index = read_u16(8);
+    let message_offset = read_u16(10);
+    let message_size = read_u16(12);
+    let message_ix_index = read_u16(14);
+
+    let this_ix = usize::from(u16::MAX);
+    require!(
+        signature_ix_index == this_ix
+            && public_key_ix_index == this_ix
+            && message_ix_index == this_ix,
+        AcademyError::InvalidAttestation
+    );
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or_else(|| error!(AcademyError::InvalidAttestation))?;
+    require!(
+        public_key == backend_signer.as_ref(),
+        AcademyError::BackendSignerMismatch
+    );
+
+    let signed_message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or_else(|| error!(AcademyError::InvalidAttestation))?;
+    require!(signed_message == message, AcademyError::InvalidAttestation);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn learner(byte: u8) -> Pubkey {
+        Pubkey::new_from_array([byte; 32])
+    }
+
+    fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        let (left, right) = if a <= b { (a, b) } else { (b, a) };
+        hashv(&[&[1u8], &left, &right]).to_bytes()
+    }
+
+    // Shared with backend/src/anchor/__tests__/distribution.test.ts so the
+    // off-chain tree builder and the on-chain verifier can't drift apart.
+    const SHARED_ROOT: [u8; 32] = [
+        0xf5, 0xe7, 0xcb, 0x56, 0x28, 0xfb, 0x18, 0xb6, 0x01, 0x9a, 0xb3, 0xcc, 0xec, 0xfe, 0x0e,
+        0x9b, 0xf5, 0x6b, 0xb7, 0x14, 0xa7, 0x38, 0x54, 0xea, 0xc9, 0x73, 0x11, 0x79, 0xcb, 0xf0,
+        0x36, 0xd5,
+    ];
+
+    fn shared_leaves() -> [[u8; 32]; 3] {
+        [
+            distribution_leaf(0, &learner(1), 100),
+            distribution_leaf(1, &learner(2), 200),
+            distribution_leaf(2, &learner(3), 300),
+        ]
+    }
+
+    #[test]
+    fn single_leaf_is_its_own_root() {
+        let leaf = distribution_leaf(0, &learner(1), 100);
+        assert!(verify_merkle_proof(&[], &leaf, leaf));
+        assert!(!verify_merkle_proof(
+            &[],
+            &leaf,
+            distribution_leaf(0, &learner(1), 101)
+        ));
+    }
+
+    #[test]
+    fn odd_leaf_is_carried_up_unpaired() {
+        let [a, b, c] = shared_leaves();
+        let root = hash_pair(hash_pair(a, b), c);
+        assert_eq!(root, SHARED_ROOT);
+
+        assert!(verify_merkle_proof(&[b, c], &root, a));
+        assert!(verify_merkle_proof(&[a, c], &root, b));
+        assert!(verify_merkle_proof(&[hash_pair(a, b)], &root, c));
+    }
+
+    #[test]
+    fn proof_rejects_a_wrong_index_or_amount() {
+        let [a, b, c] = shared_leaves();
+        let proof = [b, c];
+        assert!(verify_merkle_proof(&proof, &SHARED_ROOT, a));
+        assert!(!verify_merkle_proof(
+            &proof,
+            &SHARED_ROOT,
+            distribution_leaf(1, &learner(1), 100)
+        ));
+        assert!(!verify_merkle_proof(
+            &proof,
+            &SHARED_ROOT,
+            distribution_leaf(0, &learner(1), 1_000)
+        ));
+        assert!(!verify_merkle_proof(
+            &proof,
+            &SHARED_ROOT,
+            distribution_leaf(0, &learner(2), 100)
+        ));
+    }
+}
@@ -5,6 +5,7 @@
  */
 import { Connection, Keypair, PublicKey, Transaction } from '@solana/web3.js';
 import { AnchorProvider, Program, type Idl } from '@coral-xyz/anchor';
+import { createHash } from 'crypto';
 import * as fs from 'fs';
 import * as path from 'path';
 import { fileURLToPath } from 'url';
@@ -48,7 +49,7 @@ async function main() {
   // Test course params
   const courseId = 'solana-basics-101';
   const [coursePda] = PublicKey.findProgramAddressSync(
-    [Buffer.from('course'), Buffer.from(courseId)],
+    [Buffer.from('course'), createHash('sha256').update(courseId).digest()],
     PROGRAM_ID
   );
   console.log('Course PDA:', coursePda.toBase58());
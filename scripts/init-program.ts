@@ -58,7 +58,7 @@ async function main() {
   const normalizedIdl = { ...idl, address: PROGRAM_ID.toBase58() };
   const program = new Program(normalizedIdl as Idl, provider);
 
-  // Generate a new XP mint keypair (the program creates the mint via init)
+  // Generate a new XP mint keypair (the program creates the non-transferable Token-2022 mint)
   const xpMint = Keypair.generate();
   console.log('XP Mint (new):', xpMint.publicKey.toBase58());
 
@@ -66,13 +66,31 @@ async function main() {
   const [configPda] = PublicKey.findProgramAddressSync([Buffer.from('config')], PROGRAM_ID);
   console.log('Config PDA:', configPda.toBase58());
 
+  // Backend signer (defaults to the authority when BACKEND_SIGNER_PUBKEY is unset)
+  const backendSigner = process.env.BACKEND_SIGNER_PUBKEY
+    ? new PublicKey(process.env.BACKEND_SIGNER_PUBKEY)
+    : signer.publicKey;
+  console.log('Backend signer:', backendSigner.toBase58());
+
   // Derive minter role PDA
   const [minterPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from('minter'), signer.publicKey.toBuffer()],
+    [Buffer.from('minter'), backendSigner.toBuffer()],
     PROGRAM_ID
   );
   console.log('Minter PDA:', minterPda.toBase58());
 
+  // Derive backend signer role PDA
+  const [backendSignerRolePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('backend_signer'), backendSigner.toBuffer()],
+    PROGRAM_ID
+  );
+
+  // Derive lifetime leaderboard PDA (season 0)
+  const [leaderboardPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('leaderboard'), Buffer.alloc(4)],
+    PROGRAM_ID
+  );
+
   // Check if config already initialized
   const configInfo = await connection.getAccountInfo(configPda);
   if (configInfo) {
@@ -84,12 +102,19 @@ async function main() {
   try {
     // Build the instruction
     const ix = await (program.methods as any)
-      .initialize()
+      .initialize({
+        backendSigner,
+        xpName: 'Academy XP',
+        xpSymbol: 'XP',
+        xpUri: process.env.XP_METADATA_URI ?? '',
+      })
       .accountsPartial({
         config: configPda,
         xpMint: xpMint.publicKey,
         authority: signer.publicKey,
         backendMinterRole: minterPda,
+        backendSignerRole: backendSignerRolePda,
+        leaderboard: leaderboardPda,
         systemProgram: new PublicKey('11111111111111111111111111111111'),
         tokenProgram: TOKEN_2022_PROGRAM_ID,
       })